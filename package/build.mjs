@@ -1,8 +1,20 @@
 import dts from 'bun-plugin-dts'
+import { copyFileSync, existsSync } from 'fs'
 
 await Bun.build({
   entrypoints: ['./src/index.ts'],
   outdir: './dist',
   minify: true,
   plugins: [dts()]
-})
\ No newline at end of file
+})
+
+// bindings to the rust verifier if they were generated with `build:wasm`
+if (existsSync('./wasm/pkg')) {
+  await Bun.build({
+    entrypoints: ['./wasm/index.ts'],
+    outdir: './dist/wasm',
+    minify: true,
+    plugins: [dts()]
+  })
+  copyFileSync('./wasm/pkg/saa_bg.wasm', './dist/wasm/saa_bg.wasm')
+}
@@ -1,5 +1,17 @@
 import type { Coin } from "@keplr-wallet/types"
 
+// Messages shared with the contracts are generated from the Rust definitions
+export type {
+    AuthPayload,
+    MsgDataToSign,
+    SignedDataMsg,
+    UpdateOperation,
+    AccountCredentials,
+    CredentialInfo,
+    BlockStamp,
+    LibraryInfo,
+} from "../generated";
+
 export type CosmosMsg = 
     BankMsg | StakingMsg | GovMsg | WasmMsg | IbcMsg;
 
@@ -6,9 +6,6 @@ import { toBase64, toUtf8 } from "@cosmjs/encoding";
 import { Keplr } from "@keplr-wallet/types";
 
 
-const base64regex = /^([0-9a-zA-Z+/]{4})*(([0-9a-zA-Z+/]{2}==)|([0-9a-zA-Z+/]{3}=))?$/;
-
-
 export const makeSignDoc = (
     msgs: readonly AminoMsg[], 
     fee: StdFee, 
@@ -37,7 +34,8 @@ export const getArb36SignData = (
         type: "sign/MsgSignData",
         value: {
             signer: signerAddress,
-            data: typeof data === "string" ? data : toBase64(data),
+            // strings are encoded the same way as in `signArbitrary` of the wallets
+            data: toBase64(typeof data === "string" ? toUtf8(data) : data),
         }
     }
 )
@@ -97,9 +95,7 @@ export const getCosmosArbitraryCredential = async (
 
 
     const cosmos_arbitrary : CosmosArbitrary =  {
-        message: typeof message === "string" 
-            ? (base64regex.test(message) ? message : toBase64(toUtf8(message))) 
-            : toBase64(message),
+        message: toBase64(typeof message === "string" ? toUtf8(message) : message),
         pubkey,
         signature,
         hrp,
@@ -3,48 +3,27 @@ import type { Window as KeplrWindow } from "@keplr-wallet/types";
 import type { Eip1193Provider } from "ethers";
 
 
-export interface EthPersonalSign {
-    message: string;
-    signature: string;
-    signer: string;
-}
-
-export interface CosmosArbitrary {
-    message: string;
-    pubkey: string;
-    signature: string;
-    hrp : string
-}
-
-export interface ClientData {
-    type: string;
-    challenge: string;
-    origin: string;
-    crossOrigin: boolean;
-}
-
-export interface PasskeyCredential {
-    id: string;
-    authenticator_data: string;
-    client_data: ClientData;
-    signature: string;
-    user_handle? : string
-    pubkey?: string;
-}
-
-
-export type Credential = 
-  { eth_personal_sign: EthPersonalSign } | 
-  { cosmos_arbitrary: CosmosArbitrary } |
-  { passkey: PasskeyCredential };
-
-
-
-export interface CredentialData {
-    credentials     : Credential[];
-    with_caller?    : boolean;
-    primary_index?  : number;
-}
+// Types shared with the contracts are generated from the Rust definitions
+export type {
+    Binary,
+    EthPersonalSign,
+    CosmosArbitrary,
+    ClientData,
+    PasskeyCredential,
+    Secp256k1,
+    Secp256r1,
+    Ed25519,
+    Caller,
+    CustomCredential,
+    Siwe,
+    Credential,
+    CredentialName,
+    CredentialRole,
+    SpendingLimit,
+    CredentialSettings,
+    CredentialData,
+    Uint128,
+} from "../generated";
 
 
 
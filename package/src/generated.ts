@@ -0,0 +1,201 @@
+// Generated from the JSON schemas of the Rust types. Do not edit manually
+
+export interface AccountCredentials {
+    credentials: [Binary, CredentialInfo][];
+    native_caller: boolean;
+    verifying_id: Binary;
+}
+
+export interface AuthPayload {
+    address?: string | null;
+    credential_id?: number[] | null;
+    extension?: Binary | null;
+    hrp?: string | null;
+}
+
+export type Binary = string;
+
+export interface BlockStamp {
+    /** block height */
+    height: number;
+    /** block time in seconds */
+    time: number;
+}
+
+export interface Caller {
+    id: number[];
+}
+
+export interface ClientData {
+    challenge: string;
+    crossOrigin: boolean;
+    origin: string;
+    other_keys_can_be_added_here?: string | null;
+    type: string;
+}
+
+export interface CosmosArbitrary {
+    hrp?: string | null;
+    message: Binary;
+    pubkey: Binary;
+    signature: Binary;
+}
+
+export type Credential =
+    | { caller: Caller }
+    | { eth_personal_sign: EthPersonalSign }
+    | { cosmos_arbitrary: CosmosArbitrary }
+    | { passkey: PasskeyCredential }
+    | { secp256k1: Secp256k1 }
+    | { secp256r1: Secp256r1 }
+    | { ed25519: Ed25519 }
+    | { custom: CustomCredential }
+    | { siwe: Siwe };
+
+export interface CredentialData {
+    credentials: Credential[];
+    primary_index?: number | null;
+    /** optional settings of the credentials under the same indices */
+    settings?: CredentialSettings[] | null;
+    with_caller?: boolean | null;
+}
+
+export interface CredentialInfo {
+    /** block at which the credential has been added to the account */
+    created_at?: BlockStamp | null;
+    /** extension data */
+    extension?: Binary | null;
+    /** human readable prefix to encode from a public key */
+    hrp?: string | null;
+    /** human readable label to tell the credentials apart e.g. "MacBook Touch ID" */
+    label?: string | null;
+    /** block of the last signed action authorised by the credential */
+    last_used?: BlockStamp | null;
+    /** name of the used credential */
+    name: CredentialName;
+    /** permissions of the credential. Treated as admin if not set */
+    role?: CredentialRole | null;
+}
+
+export type CredentialName = "caller" | "cosmos_arbitrary" | "eth_personal_sign" | "passkey" | "secp256k1" | "secp256r1" | "ed25519" | "custom" | "siwe";
+
+export type CredentialRole =
+    | "admin"
+    | "executor"
+    | "viewer"
+    | "guardian";
+
+export interface CredentialSettings {
+    /** human readable label to store with the credential e.g. "YubiKey" */
+    label?: string | null;
+    /** permissions of the credential. Admin by default */
+    role?: CredentialRole | null;
+    /** maximum amounts of native tokens the credential can spend. Unrestricted by default. Tokens of other contracts e.g. CW20 ones are not limited */
+    spending_limits?: SpendingLimit[] | null;
+}
+
+export interface CustomCredential {
+    /** Id under which the credential is stored e.g. a public key */
+    id: Binary;
+    /** Signed message */
+    message: Binary;
+    /** Name of the registered verifier responsible for the credential */
+    name: string;
+    /** Any other data needed by the verifier */
+    payload?: Binary | null;
+    signature: Binary;
+}
+
+export interface Ed25519 {
+    message: Binary;
+    pubkey: Binary;
+    signature: Binary;
+}
+
+export interface EthPersonalSign {
+    message: Binary;
+    signature: Binary;
+    signer: string;
+}
+
+/** Version of the library and the credentials that it has been compiled with. Lets the clients adapt the signing flows to a deployed contract */
+export interface LibraryInfo {
+    /** credentials that can be used with the contract */
+    credentials: CredentialName[];
+    /** enabled credential features e.g. `ethereum` or `passkeys` */
+    features: string[];
+    /** name of the library crate */
+    name: string;
+    /** whether signed messages must follow the format of `MsgDataToSign` with a nonce */
+    replay: boolean;
+    /** version of the library crate */
+    version: string;
+}
+
+export interface MsgDataToSign<M = string> {
+    /** Id of the account when the contract hosts several of them in namespaced storages */
+    account_id?: string | null;
+    chain_id: string;
+    contract_address: string;
+    messages: M[];
+    nonce: string;
+}
+
+export interface PasskeyCredential {
+    /** webauthn Authenticator data */
+    authenticator_data: Binary;
+    /** Client data containg challenge, origin and type */
+    client_data: ClientData;
+    /** Passkey id */
+    id: string;
+    /** Public key is essential for verification but can be supplied on the contract side and omitted by client */
+    pubkey?: Binary | null;
+    /** Secp256r1 signature */
+    signature: Binary;
+    /** Optional user handle reserved for future use */
+    user_handle?: string | null;
+}
+
+export interface Secp256k1 {
+    hrp?: string | null;
+    message: Binary;
+    pubkey: Binary;
+    signature: Binary;
+}
+
+export interface Secp256r1 {
+    message: Binary;
+    pubkey: Binary;
+    signature: Binary;
+}
+
+export interface SignedDataMsg {
+    data: Binary;
+    payload?: AuthPayload | null;
+    signature: Binary;
+}
+
+export interface Siwe {
+    /** EIP-4361 text message signed with `personal_sign` */
+    message: string;
+    /** Signature returned by `personal_sign` encoded as hex with an optional `0x` prefix */
+    signature: string;
+}
+
+export interface SpendingLimit {
+    /** maximum amount that can be spent during the period */
+    amount: Uint128;
+    /** denomination of the native token */
+    denom: string;
+    /** length of the rolling window in seconds */
+    period: number;
+}
+
+/** A thin wrapper around u128 that is using strings for JSON encoding/decoding, such that the full u128 range can be used for clients that convert JSON numbers to floats, like JavaScript and jq. */
+export type Uint128 = string;
+
+export type UpdateOperation =
+    | { add: CredentialData }
+    | { remove: CredentialData }
+    | { replace: { add: CredentialData; remove: Binary[] } }
+    | { set_primary: Binary };
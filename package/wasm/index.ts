@@ -0,0 +1,69 @@
+// Bindings to the Rust verification code of the contracts.
+// The module under `./pkg` is generated with `bun run build:wasm`
+import init, * as bindings from "./pkg/saa";
+import type { Credential } from "../src/credentials/types";
+import type { CosmosMsg } from "../src/cosmwasm/messages";
+
+
+let loading : Promise<unknown> | undefined;
+
+
+/** Error thrown by the verification with the stable code of the Rust `AuthError` */
+export type VerificationError = Error & { code: number };
+
+
+/** Load the WebAssembly module. Called automatically by the other helpers */
+export const initVerifier = async (
+    module?: Parameters<typeof init>[0]
+) : Promise<void> => {
+    loading ??= init(module);
+    await loading;
+}
+
+
+/** 
+ * JSON text of the message that the credentials must sign for the replay protection.
+ * `accountId` must be passed for the accounts hosted by a shared contract in a namespaced storage
+ */
+export const dataToSign = async (
+    chainId         : string,
+    contractAddress : string,
+    messages        : (CosmosMsg | unknown)[],
+    nonce           : string | number,
+    accountId?      : string,
+) : Promise<string> => {
+    await initVerifier();
+    return bindings.dataToSign(chainId, contractAddress, messages, nonce.toString(), accountId);
+}
+
+
+/** 
+ * Throws an error with the reason if the credential would be rejected by the contracts.
+ * The `code` property of the error is the stable numeric code of the Rust `AuthError` e.g. `200` for invalid signatures
+ */
+export const verifyCredential = async (
+    credential : Credential
+) : Promise<void> => {
+    await initVerifier();
+    bindings.verify(credential);
+}
+
+
+export const isValidCredential = async (
+    credential : Credential
+) : Promise<boolean> => {
+    await initVerifier();
+    return bindings.isValid(credential);
+}
+
+
+/** Base64 encoded id under which the contracts store the credential */
+export const getCredentialId = async (
+    credential : Credential
+) : Promise<string> => {
+    await initVerifier();
+    return bindings.credentialId(credential);
+}
+
+
+export { bindings };
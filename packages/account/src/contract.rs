@@ -0,0 +1,71 @@
+#[cfg(not(feature = "library"))]
+use cosmwasm_std::entry_point;
+use cosmwasm_std::{to_json_binary, Binary, Deps, DepsMut, Env, MessageInfo, Response};
+use smart_account_auth::{
+    lock_account, verify_queries,
+    storage::{ensure_unlocked, get_account_lock, CALLER},
+    AuthError,
+};
+
+use crate::{
+    msg::{ExecuteMsg, InstantiateMsg, QueryMsg},
+    ContractError,
+};
+
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn instantiate(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    msg: InstantiateMsg,
+) -> Result<Response, ContractError> {
+    let events = msg.account_data.save(deps.api, deps.storage, &env, &info, None)?;
+    Ok(Response::new()
+        .add_attribute("action", "instantiate")
+        .add_events(events))
+}
+
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn execute(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    msg: ExecuteMsg,
+) -> Result<Response, ContractError> {
+    match msg {
+        ExecuteMsg::Execute { msgs } => {
+            ensure_unlocked(deps.storage)?;
+            let caller = CALLER.may_load(deps.storage)?.flatten();
+            if caller.as_deref() != Some(info.sender.as_str()) {
+                return Err(AuthError::Unauthorized.into());
+            }
+            Ok(Response::new()
+                .add_attribute("action", "execute")
+                .add_messages(msgs))
+        }
+        ExecuteMsg::Lock { signed } => {
+            let events = lock_account(deps.api, deps.storage, &env, signed, None)?;
+            Ok(Response::new().add_attribute("action", "lock").add_events(events))
+        }
+        ExecuteMsg::Unlock { signers } => {
+            let events = signers.unlock(deps.api, deps.storage, &env, None)?;
+            Ok(Response::new().add_attribute("action", "unlock").add_events(events))
+        }
+        auth => Ok(auth.dispatch_auth(deps, &env, &info)?),
+    }
+}
+
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn query(deps: Deps, env: Env, msg: QueryMsg) -> Result<Binary, ContractError> {
+    let res = match msg {
+        QueryMsg::AccountLock {} => to_json_binary(&get_account_lock(deps.storage)?),
+        QueryMsg::ValidSignature { signed } => to_json_binary(
+            &verify_queries(deps.api, deps.storage, &env, signed, None).is_ok()
+        ),
+        auth => return Ok(auth.dispatch_auth(deps)?),
+    }?;
+    Ok(res)
+}
@@ -0,0 +1,13 @@
+use cosmwasm_std::StdError;
+use smart_account_auth::AuthError;
+use thiserror::Error;
+
+
+#[derive(Error, Debug, PartialEq)]
+pub enum ContractError {
+    #[error("{0}")]
+    Std(#[from] StdError),
+
+    #[error("{0}")]
+    Auth(#[from] AuthError),
+}
@@ -0,0 +1,7 @@
+//! Reference smart account contract showing how to store credentials, authorise actions
+//! with them and manage them through the smart account auth library
+pub mod contract;
+pub mod error;
+pub mod msg;
+
+pub use error::ContractError;
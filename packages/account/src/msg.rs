@@ -0,0 +1,50 @@
+use cosmwasm_schema::QueryResponses;
+use cosmwasm_std::CosmosMsg;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use smart_account_auth::{messages::SignedDataMsg, saa_execute, saa_query, AccountLock, CredentialData};
+
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct InstantiateMsg {
+    /// credentials of the account signed with the nonce 0
+    pub account_data: CredentialData,
+}
+
+
+/// Also includes `UpdateCredentials` and `ExecuteSigned` added by `#[saa_execute]`
+#[saa_execute]
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+#[serde(deny_unknown_fields, rename_all = "snake_case")]
+pub enum ExecuteMsg {
+    /// Execute the messages on behalf of the account. The sender 
+    /// must be the native caller stored in the account
+    Execute {
+        msgs: Vec<CosmosMsg>,
+    },
+    /// Disable all the signed actions of the account
+    Lock {
+        signed: SignedDataMsg,
+    },
+    /// Lift the lock with approval of the admin signers
+    Unlock {
+        signers: CredentialData,
+    },
+}
+
+
+/// Also includes `Credentials`, `AccountNumber` and `LibraryInfo` added by `#[saa_query]`
+#[saa_query]
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema, QueryResponses)]
+#[serde(deny_unknown_fields, rename_all = "snake_case")]
+pub enum QueryMsg {
+    /// Current lock of the account
+    #[returns(Option<AccountLock>)]
+    AccountLock {},
+    /// Whether the signed data is valid for the current nonce without consuming it
+    #[returns(bool)]
+    ValidSignature {
+        signed: SignedDataMsg,
+    },
+}
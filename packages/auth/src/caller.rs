@@ -54,7 +54,7 @@ impl Verifiable for Caller {
         if !(id.len() > 3) {
             return Err(AuthError::MissingData("Caller must have an id".to_string()));
         }
-        ensure!(String::from_utf8(id).is_ok(), AuthError::generic("Can't derive calling address"));
+        ensure!(String::from_utf8(id).is_ok(), AuthError::InvalidData("Can't derive calling address".to_string()));
         Ok(())
     }
 
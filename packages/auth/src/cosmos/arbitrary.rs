@@ -2,7 +2,7 @@
 #[cfg(any(feature = "wasm", feature = "native"))]
 use {
     saa_common::{hashes::sha256, utils::pubkey_to_address, ensure},
-    super::utils::preamble_msg_arb_036
+    super::utils::sign_doc_arb_036
 };
 use saa_common::{AuthError, Binary, CredentialId, String, ToString, Verifiable};
 use saa_schema::wasm_serde;
@@ -19,10 +19,10 @@ pub struct CosmosArbitrary {
 #[cfg(any(feature = "wasm", feature = "native"))]
 impl CosmosArbitrary {
     fn message_digest(&self) -> Result<Vec<u8>, AuthError> {
-        ensure!(self.hrp.is_some(), AuthError::Generic("Must provide prefix for the public key".to_string()));
-        Ok(sha256(&preamble_msg_arb_036(
+        ensure!(self.hrp.is_some(), AuthError::MissingData("Must provide prefix for the public key".to_string()));
+        Ok(sha256(sign_doc_arb_036(
             pubkey_to_address(&self.pubkey, self.hrp.as_ref().unwrap())?.as_str(),
-            &self.message.to_string()
+            &self.message
         ).as_bytes()))
     }
 }
@@ -1,3 +1,93 @@
+use cosmwasm_std::testing::mock_dependencies;
+use saa_common::{hashes::sha256, utils::pubkey_to_address, vec, AuthError, Binary, Verifiable};
 
+use super::{utils::{preamble_msg_arb_036, sign_doc_arb_036, AminoJson}, CosmosArbitrary};
 
-mod tests {}
\ No newline at end of file
+
+const SIGNER: &str = "cosmos1rhzuxlhzhq6p8w6hm36n8kanvu4e0hmv8u8suv";
+
+
+// The documents and signatures below were produced outside of this crate by a Node script that
+// serializes like `serializeSignDoc` of cosmjs (sorted keys and escaped `&`, `<` and `>`)
+// and signs with the `secp256k1` curve of OpenSSL. They are not captured from a wallet:
+// sign docs and signatures produced by Keplr, Leap, Cosmostation and Ledger in amino JSON mode
+// are still missing, so the compatibility with the wallets themselves isn't covered by the tests
+
+
+#[test]
+fn sign_doc_vectors() {
+    let vectors : [(&[u8], &str, &str, &str); 3] = [
+        (
+            b"hello",
+            "aGVsbG8=",
+            r#"{"account_number":"0","chain_id":"","fee":{"amount":[],"gas":"0"},"memo":"","msgs":[{"type":"sign/MsgSignData","value":{"data":"aGVsbG8=","signer":"cosmos1rhzuxlhzhq6p8w6hm36n8kanvu4e0hmv8u8suv"}}],"sequence":"0"}"#,
+            "0058526ae02aec00ea34c164ae548107c6914e3daa4221daa3b6a6fefae50d0d"
+        ),
+        (
+            "{\"msg\":\"héllo <world> & \\\"friends\\\" 🚀\"}".as_bytes(),
+            "eyJtc2ciOiJow6lsbG8gPHdvcmxkPiAmIFwiZnJpZW5kc1wiIPCfmoAifQ==",
+            r#"{"account_number":"0","chain_id":"","fee":{"amount":[],"gas":"0"},"memo":"","msgs":[{"type":"sign/MsgSignData","value":{"data":"eyJtc2ciOiJow6lsbG8gPHdvcmxkPiAmIFwiZnJpZW5kc1wiIPCfmoAifQ==","signer":"cosmos1rhzuxlhzhq6p8w6hm36n8kanvu4e0hmv8u8suv"}}],"sequence":"0"}"#,
+            "df889762d5030b1a889aa2afa36017ec32142c09340f9ae58270c291445855dd"
+        ),
+        (
+            &[0, 1, 254, 255],
+            "AAH+/w==",
+            r#"{"account_number":"0","chain_id":"","fee":{"amount":[],"gas":"0"},"memo":"","msgs":[{"type":"sign/MsgSignData","value":{"data":"AAH+/w==","signer":"cosmos1rhzuxlhzhq6p8w6hm36n8kanvu4e0hmv8u8suv"}}],"sequence":"0"}"#,
+            "bd01948bedcd1044598a51c7811e14e70edef5c910573de92bb0edd5737be147"
+        ),
+    ];
+    for (data, encoded, expected, digest) in vectors {
+        let doc = sign_doc_arb_036(SIGNER, data);
+        assert_eq!(doc, expected);
+        assert_eq!(doc, preamble_msg_arb_036(SIGNER, encoded));
+        assert_eq!(hex::encode(sha256(doc.as_bytes())), digest);
+    }
+}
+
+
+#[test]
+fn strings_are_escaped_like_amino() {
+    let doc = preamble_msg_arb_036(SIGNER, "say \"hi\" <b>&\n\t\u{1} ünï 🚀 \\");
+    assert_eq!(
+        doc,
+        r#"{"account_number":"0","chain_id":"","fee":{"amount":[],"gas":"0"},"memo":"","msgs":[{"type":"sign/MsgSignData","value":{"data":"say \"hi\" \u003cb\u003e\u0026\n\t\u0001 ünï 🚀 \\","signer":"cosmos1rhzuxlhzhq6p8w6hm36n8kanvu4e0hmv8u8suv"}}],"sequence":"0"}"#
+    );
+}
+
+
+#[test]
+fn externally_signed_message_is_verified() {
+    let deps = mock_dependencies();
+    let credential = CosmosArbitrary {
+        pubkey: Binary::from_base64("A1vl6UeCCWdKluYPHwN/YXZUD9AB+h1kaUdwxWp3CcQs").unwrap(),
+        signature: Binary::from_base64(
+            "XSc6gbuP/j6Sta10XWDuRJTK+r/vM7e7qofIPmOpBKRHWDFgqvj7qXKc2TOsUJNe+dG/AoTZRtjZAQmSAxNGEQ=="
+        ).unwrap(),
+        message: Binary::from(b"Sign in to <app> & \"agree\"".as_slice()),
+        hrp: Some("cosmos".to_string()),
+    };
+    let signer = pubkey_to_address(&credential.pubkey, "cosmos").unwrap();
+    assert_eq!(signer, "cosmos1rcl29p79agjrjnd2quk2s9u9jypu4lr3hyxem5");
+    assert_eq!(
+        hex::encode(sha256(sign_doc_arb_036(&signer, &credential.message).as_bytes())),
+        "d2e262b24a95998f902b0345407dd49b08a6a98109416a21a2d9c97aea56118b"
+    );
+    credential.verify_cosmwasm(deps.as_ref().api).unwrap();
+
+    let tampered = CosmosArbitrary { message: Binary::from(b"Sign in to <app>".as_slice()), ..credential.clone() };
+    assert!(matches!(tampered.verify_cosmwasm(deps.as_ref().api), Err(AuthError::Signature(_))));
+}
+
+
+#[test]
+fn object_keys_are_sorted() {
+    let value = AminoJson::object([
+        ("b", AminoJson::string("1")),
+        ("a", AminoJson::Array(vec![
+            AminoJson::object([("z", AminoJson::string("")), ("Z", AminoJson::string(""))]),
+            AminoJson::Array(vec![]),
+        ])),
+        ("a_b", AminoJson::object([])),
+    ]);
+    assert_eq!(value.to_canonical(), r#"{"a":[{"Z":"","z":""},[]],"a_b":{},"b":"1"}"#);
+}
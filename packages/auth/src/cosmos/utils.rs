@@ -1,10 +1,111 @@
-use saa_common::{format, String};
+use saa_common::{vec, Binary, String, ToString, Vec};
 
 
-pub fn preamble_msg_arb_036(signer: &str, data: &str) -> String {
-    format!(
-        "{{\"account_number\":\"0\",\"chain_id\":\"\",\"fee\":{{\"amount\":[],\"gas\":\"0\"}},\"memo\":\"\",\"msgs\":[{{\"type\":\"sign/MsgSignData\",\"value\":{{\"data\":\"{}\",\"signer\":\"{}\"}}}}],\"sequence\":\"0\"}}", 
-        data, signer
-    )
+/// Value of an amino JSON document serialized canonically in the same way as `serializeSignDoc`
+/// of cosmjs used by the wallets: keys of the objects are sorted, there is no whitespace
+/// and `&`, `<` and `>` are escaped as unicode sequences like the Cosmos SDK does.
+/// Numbers are represented with strings as it is done in amino
+#[derive(Clone, Debug, PartialEq)]
+pub enum AminoJson {
+    String(String),
+    Array(Vec<AminoJson>),
+    Object(Vec<(String, AminoJson)>),
 }
 
+
+impl AminoJson {
+
+    pub fn string(value: impl ToString) -> Self {
+        AminoJson::String(value.to_string())
+    }
+
+    pub fn object<const N: usize>(fields: [(&str, AminoJson); N]) -> Self {
+        AminoJson::Object(fields.into_iter().map(|(k, v)| (k.to_string(), v)).collect())
+    }
+
+    pub fn to_canonical(&self) -> String {
+        let mut out = String::new();
+        self.write(&mut out);
+        out
+    }
+
+    fn write(&self, out: &mut String) {
+        match self {
+            AminoJson::String(value) => write_escaped(value, out),
+            AminoJson::Array(items) => {
+                out.push('[');
+                for (i, item) in items.iter().enumerate() {
+                    if i > 0 { out.push(','); }
+                    item.write(out);
+                }
+                out.push(']');
+            },
+            AminoJson::Object(fields) => {
+                let mut fields : Vec<&(String, AminoJson)> = fields.iter().collect();
+                fields.sort_by(|a, b| a.0.cmp(&b.0));
+                out.push('{');
+                for (i, (key, value)) in fields.into_iter().enumerate() {
+                    if i > 0 { out.push(','); }
+                    write_escaped(key, out);
+                    out.push(':');
+                    value.write(out);
+                }
+                out.push('}');
+            }
+        }
+    }
+}
+
+
+/// Quoted string escaped like `JSON.stringify` followed by the HTML escaping of amino
+fn write_escaped(value: &str, out: &mut String) {
+    const HEX: &[u8; 16] = b"0123456789abcdef";
+    out.push('"');
+    for c in value.chars() {
+        match c {
+            '"'  => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            '\t' => out.push_str("\\t"),
+            '\u{8}' => out.push_str("\\b"),
+            '\u{c}' => out.push_str("\\f"),
+            '&' | '<' | '>' | '\u{0}'..='\u{1f}' => {
+                let code = c as usize;
+                out.push_str("\\u00");
+                out.push(HEX[code >> 4] as char);
+                out.push(HEX[code & 0xf] as char);
+            },
+            c => out.push(c),
+        }
+    }
+    out.push('"');
+}
+
+
+/// Sign document of ADR-036 for arbitrary data that the wallets encode with base64
+pub fn sign_doc_arb_036(signer: &str, data: &[u8]) -> String {
+    preamble_msg_arb_036(signer, &Binary::from(data).to_base64())
+}
+
+
+/// Sign document of ADR-036 for data that is already encoded
+pub fn preamble_msg_arb_036(signer: &str, data: &str) -> String {
+    AminoJson::object([
+        ("chain_id", AminoJson::string("")),
+        ("account_number", AminoJson::string("0")),
+        ("sequence", AminoJson::string("0")),
+        ("fee", AminoJson::object([
+            ("gas", AminoJson::string("0")),
+            ("amount", AminoJson::Array(vec![])),
+        ])),
+        ("msgs", AminoJson::Array(vec![AminoJson::object([
+            ("type", AminoJson::string("sign/MsgSignData")),
+            ("value", AminoJson::object([
+                ("signer", AminoJson::string(signer)),
+                ("data", AminoJson::string(data)),
+            ])),
+        ])])),
+        ("memo", AminoJson::string("")),
+    ]).to_canonical()
+}
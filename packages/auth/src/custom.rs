@@ -0,0 +1,96 @@
+//! Credentials of the kinds defined by the integrators and verified 
+//! by the verifiers that the host passes to the checks under their names
+
+use saa_common::{ensure, AuthError, Binary, CredentialId, String, ToString, Verifiable};
+use saa_schema::wasm_serde;
+
+#[cfg(feature = "wasm")]
+use saa_common::cosmwasm::Api;
+
+
+#[wasm_serde]
+pub struct CustomCredential {
+    /// Name of the registered verifier responsible for the credential
+    pub name: String,
+    /// Id under which the credential is stored e.g. a public key
+    pub id: Binary,
+    /// Signed message
+    pub message: Binary,
+    pub signature: Binary,
+    /// Any other data needed by the verifier
+    pub payload: Option<Binary>,
+}
+
+
+/// Verification logic of a custom credential kind provided by the host
+pub trait CustomVerifier {
+
+    /// Name of the credentials handled by the verifier
+    fn name(&self) -> &'static str;
+
+    fn validate(&self, _credential: &CustomCredential) -> Result<(), AuthError> {
+        Ok(())
+    }
+
+    #[cfg(feature = "native")]
+    fn verify(&self, credential: &CustomCredential) -> Result<(), AuthError> {
+        Err(AuthError::UnsupportedCredential(credential.name.clone()))
+    }
+
+    #[cfg(feature = "wasm")]
+    fn verify_cosmwasm(&self, api: &dyn Api, credential: &CustomCredential) -> Result<(), AuthError>;
+}
+
+
+impl CustomCredential {
+
+    /// Verifier with the name of the credential out of the ones passed by the host
+    pub fn verifier<'a>(&self, verifiers: &[&'a dyn CustomVerifier]) -> Result<&'a dyn CustomVerifier, AuthError> {
+        verifiers
+            .iter()
+            .find(|v| v.name() == self.name)
+            .copied()
+            .ok_or(AuthError::UnsupportedCredential(self.name.clone()))
+    }
+
+    #[cfg(feature = "native")]
+    pub fn verify_with(&self, verifiers: &[&dyn CustomVerifier]) -> Result<(), AuthError> {
+        self.validate()?;
+        let verifier = self.verifier(verifiers)?;
+        verifier.validate(self)?;
+        verifier.verify(self)
+    }
+
+    #[cfg(feature = "wasm")]
+    pub fn verify_cosmwasm_with(&self, api: &dyn Api, verifiers: &[&dyn CustomVerifier]) -> Result<(), AuthError> {
+        self.validate()?;
+        let verifier = self.verifier(verifiers)?;
+        verifier.validate(self)?;
+        verifier.verify_cosmwasm(api, self)
+    }
+}
+
+
+/// Without the verifiers of the host the credentials can only be checked for the structure
+impl Verifiable for CustomCredential {
+
+    fn id(&self) -> CredentialId {
+        self.id.to_vec()
+    }
+
+    fn validate(&self) -> Result<(), AuthError> {
+        ensure!(!self.name.is_empty(), AuthError::MissingData("Custom credential must have a name".to_string()));
+        ensure!(!self.id.is_empty(), AuthError::MissingData("Custom credential must have an id".to_string()));
+        Ok(())
+    }
+
+    #[cfg(feature = "native")]
+    fn verify(&self) -> Result<(), AuthError> {
+        Err(AuthError::UnsupportedCredential(self.name.clone()))
+    }
+
+    #[cfg(feature = "wasm")]
+    fn verify_cosmwasm(&self, _: &dyn Api) -> Result<(), AuthError> {
+        Err(AuthError::UnsupportedCredential(self.name.clone()))
+    }
+}
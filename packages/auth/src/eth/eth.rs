@@ -1,5 +1,5 @@
 
-use saa_common::{CredentialId, AuthError, Binary, String, ToString, Verifiable };
+use saa_common::{CredentialId, AuthError, Binary, String, ToString, Verifiable, Vec };
 use saa_schema::wasm_serde;
 
 #[cfg(any(feature = "wasm", feature = "native"))]
@@ -17,6 +17,16 @@ pub struct EthPersonalSign {
 }
 
 
+impl EthPersonalSign {
+
+    fn signer_bytes(&self) -> Result<Vec<u8>, AuthError> {
+        let signer = self.signer.strip_prefix("0x")
+            .ok_or(AuthError::InvalidEncoding("Signer must start with 0x".to_string()))?;
+        hex::decode(signer).map_err(|e| AuthError::InvalidEncoding(e.to_string()))
+    }
+}
+
+
 impl Verifiable for EthPersonalSign {
 
     fn id(&self) -> CredentialId {
@@ -33,13 +43,12 @@ impl Verifiable for EthPersonalSign {
 
     fn validate(&self) -> Result<(), AuthError> {
         if self.signature.len() < 65 {
-            return Err(AuthError::MissingData("Signature must be at least 65 bytes".to_string()));
+            return Err(AuthError::MalformedSignature("must be at least 65 bytes".to_string()));
         }
-        let signer_bytes = hex::decode(&self.signer[2..])
-            .map_err(|e| AuthError::generic(e.to_string()))?;
+        let signer_bytes = self.signer_bytes()?;
 
         if signer_bytes.len() != 20 {
-            return Err(AuthError::MissingData("Signer must be 20 bytes".to_string()));
+            return Err(AuthError::InvalidData("Signer must be 20 bytes".to_string()));
         }
         Ok(())
     }
@@ -47,6 +56,7 @@ impl Verifiable for EthPersonalSign {
 
     #[cfg(feature = "native")] 
     fn verify(&self) -> Result<(), AuthError> {
+        self.validate()?;
         let signature = &self.signature.to_vec();
         let key_data = saa_common::crypto::secp256k1_recover_pubkey(
             &preamble_msg_eth(&self.message), 
@@ -54,11 +64,10 @@ impl Verifiable for EthPersonalSign {
             get_recovery_param(signature[64])?
         )?;
         let hash = saa_common::hashes::keccak256(&key_data[1..]);
-        let recovered = String::from_utf8(
-            hash[12..].to_vec()
-        ).map_err(|_| AuthError::RecoveryMismatch)?;
-    
-        ensure!(self.signer == recovered, AuthError::RecoveryMismatch);
+
+        let addr_bytes = self.signer_bytes()?;
+
+        ensure!(addr_bytes == hash[12..], AuthError::RecoveryMismatch);
         Ok(())
     }
 
@@ -76,8 +85,7 @@ impl Verifiable for EthPersonalSign {
     
         let hash = saa_common::hashes::keccak256(&key_data[1..]);
 
-        let addr_bytes = hex::decode(&self.signer[2..])
-            .map_err(|e| AuthError::generic(e.to_string()))?;
+        let addr_bytes = self.signer_bytes()?;
         
         ensure!(addr_bytes == hash[12..], AuthError::RecoveryMismatch);
         Ok(())
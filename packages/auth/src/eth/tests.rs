@@ -29,4 +29,19 @@ mod tests {
         println!("Res: {:?}", res);
         assert!(res.is_ok())
     }
+
+
+    #[test]
+    fn short_signers_are_rejected() {
+        for signer in ["", "0", "ac03048da6065e584d52007e22c69174cdf2b91a"] {
+            let cred = EthPersonalSign {
+                signer: signer.to_string(),
+                signature: Binary::new(vec![0; 65]),
+                message: Binary::default(),
+            };
+            assert!(cred.validate().is_err());
+            #[cfg(feature = "native")]
+            assert!(cred.verify().is_err());
+        }
+    }
 }
\ No newline at end of file
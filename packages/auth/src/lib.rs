@@ -5,3 +5,7 @@ pub mod eth;
 pub mod cosmos;
 pub mod caller;
 pub mod passkey;
+pub mod siwe;
+
+#[cfg(feature = "custom")]
+pub mod custom;
@@ -1,6 +1,8 @@
 mod passkey;
 
 pub mod utils;
+#[cfg(feature = "authenticator")]
+pub mod authenticator;
 pub use passkey::*;
 
 #[cfg(test)]
@@ -0,0 +1,167 @@
+//! Software WebAuthn authenticator for generating passkey credentials in tests and fixtures
+
+use p256::ecdsa::{signature::hazmat::PrehashSigner, Signature, SigningKey};
+use saa_common::{hashes::sha256, to_json_binary, AuthError, Binary};
+
+use super::{ClientData, PasskeyCredential};
+
+
+/// User Present flag of the authenticator data
+pub const FLAG_USER_PRESENT : u8 = 0x01;
+/// User Verified flag of the authenticator data
+pub const FLAG_USER_VERIFIED : u8 = 0x04;
+
+
+/// Variations of an assertion used to cover the edge cases of verification
+#[derive(Clone, Debug, PartialEq)]
+pub struct AssertionOptions {
+    /// `crossOrigin` value of the client data
+    pub cross_origin: bool,
+    /// Include the `other_keys_can_be_added_here` entry in the client data
+    pub other_keys: bool,
+    /// Output the signature with the high S value instead of the normalized one
+    pub high_s: bool,
+    /// Set the User Verified flag in addition to User Present
+    pub user_verified: bool,
+}
+
+
+impl Default for AssertionOptions {
+    fn default() -> Self {
+        Self {
+            cross_origin: false,
+            other_keys: false,
+            high_s: false,
+            user_verified: true,
+        }
+    }
+}
+
+
+/// Deterministic authenticator holding a single P-256 key bound to an origin.
+/// Produces assertions in the same format as the browsers
+pub struct SoftAuthenticator {
+    key: SigningKey,
+    /// Id of the generated credentials
+    pub credential_id: String,
+    /// Origin put in the client data
+    pub origin: String,
+    /// Relying party id whose hash starts the authenticator data
+    pub rp_id: String,
+    /// Signature counter incremented after every assertion
+    pub sign_count: u32,
+}
+
+
+impl SoftAuthenticator {
+
+    /// Authenticator with a key derived from the hash of the seed
+    pub fn from_seed(seed: &[u8], origin: &str) -> Result<Self, AuthError> {
+        Self::from_secret(&sha256(seed), origin)
+    }
+
+    /// Authenticator with the given private key.
+    /// The relying party id is the host of the origin and the credential id is derived from the public key
+    pub fn from_secret(secret: &[u8], origin: &str) -> Result<Self, AuthError> {
+        let key = SigningKey::from_slice(secret)
+            .map_err(|e| AuthError::Crypto(e.to_string()))?;
+
+        let rp_id = origin
+            .split("://").last().unwrap_or(origin)
+            .split([':', '/']).next().unwrap_or_default()
+            .to_string();
+
+        let point = key.verifying_key().to_encoded_point(false);
+        let credential_id = Binary::from(&sha256(point.as_bytes())[..16]).to_base64url();
+
+        Ok(Self {
+            key,
+            credential_id,
+            origin: origin.to_string(),
+            rp_id,
+            sign_count: 0,
+        })
+    }
+
+    pub fn with_credential_id(mut self, id: &str) -> Self {
+        self.credential_id = id.to_string();
+        self
+    }
+
+    pub fn with_rp_id(mut self, rp_id: &str) -> Self {
+        self.rp_id = rp_id.to_string();
+        self
+    }
+
+    /// Uncompressed public key as exported by the browsers
+    pub fn public_key(&self) -> Binary {
+        self.key.verifying_key().to_encoded_point(false).as_bytes().to_vec().into()
+    }
+
+    /// Hash of the relying party id followed by the flags and the current signature counter
+    pub fn authenticator_data(&self, user_verified: bool) -> Binary {
+        let mut data = sha256(self.rp_id.as_bytes());
+        data.push(if user_verified {
+            FLAG_USER_PRESENT | FLAG_USER_VERIFIED
+        } else {
+            FLAG_USER_PRESENT
+        });
+        data.extend_from_slice(&self.sign_count.to_be_bytes());
+        data.into()
+    }
+
+    pub fn client_data(&self, challenge: &str, options: &AssertionOptions) -> ClientData {
+        ClientData::new(
+            "webauthn.get".into(),
+            challenge.to_string(),
+            self.origin.clone(),
+            options.cross_origin,
+            options.other_keys,
+        )
+    }
+
+    /// Sign the given base64url challenge and increment the signature counter
+    pub fn assert(
+        &mut self,
+        challenge: &str,
+        options: &AssertionOptions,
+    ) -> Result<PasskeyCredential, AuthError> {
+        let authenticator_data = self.authenticator_data(options.user_verified);
+        let client_data = self.client_data(challenge, options);
+
+        let mut signed = authenticator_data.to_vec();
+        signed.extend(sha256(to_json_binary(&client_data)?.as_slice()));
+
+        let signature : Signature = self.key
+            .sign_prehash(&sha256(&signed))
+            .map_err(|e| AuthError::Crypto(e.to_string()))?;
+        let signature = signature.normalize_s().unwrap_or(signature);
+        let signature = if options.high_s {
+            let (r, s) = signature.split_scalars();
+            Signature::from_scalars(r, -s).map_err(|e| AuthError::Crypto(e.to_string()))?
+        } else {
+            signature
+        };
+
+        self.sign_count += 1;
+
+        Ok(PasskeyCredential {
+            id: self.credential_id.clone(),
+            signature: signature.to_bytes().to_vec().into(),
+            authenticator_data,
+            client_data,
+            user_handle: None,
+            pubkey: Some(self.public_key()),
+        })
+    }
+
+    /// Assertion over a message encoded as a challenge in the same way as the browser helpers do
+    pub fn assert_message(
+        &mut self,
+        message: &[u8],
+        options: &AssertionOptions,
+    ) -> Result<PasskeyCredential, AuthError> {
+        let challenge = Binary::from(message).to_base64url();
+        self.assert(&challenge, options)
+    }
+}
@@ -11,7 +11,6 @@ use {
 // Enforce serde for now until figuring how to rename fields with other serialization libraries
 #[derive(
     Clone, Debug, PartialEq,
-    ::saa_schema::serde::Serialize,
     ::saa_schema::serde::Deserialize
 )]
 // Manual derivation due to #[deny_unknown_fields] in the macro
@@ -39,11 +38,33 @@ pub struct ClientData {
     pub origin: String,
     #[serde(rename = "crossOrigin")]
     pub cross_origin: bool,
-    #[serde(skip_serializing_if = "Option::is_none")]
     pub other_keys_can_be_added_here: Option<String>,
 }
 
 
+/// Same field order as in the JSON produced by the browsers. The missing `other_keys_can_be_added_here`
+/// is only omitted in human readable formats since the binary ones rely on the position of the fields
+impl ::saa_schema::serde::Serialize for ClientData {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::saa_schema::serde::Serializer,
+    {
+        use ::saa_schema::serde::ser::SerializeStruct;
+
+        let skip_others = serializer.is_human_readable() && self.other_keys_can_be_added_here.is_none();
+        let mut state = serializer.serialize_struct("ClientData", if skip_others { 4 } else { 5 })?;
+        state.serialize_field("type", &self.ty)?;
+        state.serialize_field("challenge", &self.challenge)?;
+        state.serialize_field("origin", &self.origin)?;
+        state.serialize_field("crossOrigin", &self.cross_origin)?;
+        if !skip_others {
+            state.serialize_field("other_keys_can_be_added_here", &self.other_keys_can_be_added_here)?;
+        }
+        state.end()
+    }
+}
+
+
 impl ClientData {
     pub fn new(ty: String, challenge: String, origin: String, cross_origin: bool, others: bool) -> Self {
         Self {
@@ -120,6 +141,17 @@ impl PasskeyCredential {
     }
 }
 
+impl PasskeyCredential {
+
+    /// Check that the assertion has been made at the origin stored during the registration
+    pub fn assert_extension(&self, stored: &PasskeyExtension) -> Result<(), AuthError> {
+        ensure!(self.client_data.origin == stored.origin, AuthError::OriginMismatch);
+        ensure!(!self.client_data.cross_origin || stored.cross_origin, AuthError::OriginMismatch);
+        Ok(())
+    }
+}
+
+
 impl Verifiable for PasskeyCredential {
 
     fn id(&self) -> CredentialId {
@@ -127,11 +159,15 @@ impl Verifiable for PasskeyCredential {
     }
 
     fn validate(&self) -> Result<(), AuthError> {
-        ensure!(self.authenticator_data.len() >= 37, AuthError::generic("Invalid authenticator data"));
-        ensure!(self.signature.len() > 0, AuthError::generic("Empty signature"));
-        ensure!(self.client_data.challenge.len() > 0, AuthError::generic("Empty challenge"));
-        ensure!(self.client_data.ty == "webauthn.get", AuthError::generic("Invalid client data type"));
-        ensure!(self.pubkey.is_some(), AuthError::generic("Missing public key"));
+        ensure!(self.authenticator_data.len() >= 37, AuthError::InvalidData("Invalid authenticator data".to_string()));
+        ensure!(!self.signature.is_empty(), AuthError::MalformedSignature("empty".to_string()));
+        ensure!(!self.client_data.challenge.is_empty(), AuthError::MissingData("Empty challenge".to_string()));
+        ensure!(self.client_data.ty == "webauthn.get", AuthError::InvalidData("Invalid client data type".to_string()));
+        ensure!(self.pubkey.is_some(), AuthError::MissingData("Missing public key".to_string()));
+        ensure!(
+            super::utils::rp_id_matches(&self.client_data.origin, &self.authenticator_data[..32]),
+            AuthError::DigestMismatch
+        );
         Ok(())
     }
 
@@ -142,7 +178,7 @@ impl Verifiable for PasskeyCredential {
             &self.signature,
             self.pubkey.as_ref().unwrap()
         )?;
-        ensure!(res, AuthError::generic("Signature verification failed"));
+        ensure!(res, AuthError::Signature("Signature verification failed".to_string()));
         Ok(())
     }
 
@@ -5,7 +5,7 @@ mod tests {
     use cosmwasm_std::{coin, testing::mock_dependencies, CosmosMsg};
     use saa_common::{messages::MsgDataToSign, to_json_binary, Binary, Verifiable};
     use serde::{Deserialize, Serialize};
-    use crate::passkey::{utils::base64_to_url, ClientData, PasskeyCredential};
+    use crate::passkey::{ClientData, PasskeyCredential};
     
 
     #[test]
@@ -56,11 +56,12 @@ mod tests {
             chain_id: "pion-1".to_string(),
             contract_address: "neutron1uf26dql0t895fzltzp3q7t5g7q77e6e8d790jf7lp78kdwcyahlqe38qg5".to_string(),
             messages: vec![String::from("Create Proxy Account")],
-            nonce: "0".to_string()
+            nonce: "0".to_string(),
+            account_id: None,
         };
 
         let binary =  to_json_binary(&sign_data).unwrap();
-        let challenge = base64_to_url(&binary.to_base64());
+        let challenge = binary.to_base64url();
 
         let credential = PasskeyCredential { 
             id: String::default(),
@@ -111,11 +112,12 @@ mod tests {
                     amount: coin(1000000, "ustars")
                 })] 
             }],
-            nonce: "1".to_string()
+            nonce: "1".to_string(),
+            account_id: None,
         };
         
         let binary =  to_json_binary(&sign_data).unwrap();
-        let challenge = base64_to_url(&binary.to_base64());
+        let challenge = binary.to_base64url();
         
         let json_str = r#"{"chain_id":"elgafar-1","contract_address":"stars156t98r39hf3yr8n76e24asywy45y4lthwfs5349q0ucp28wqp9lsquujva","messages":[{"execute":{"msgs":[{"staking":{"delegate":{"validator":"starsvaloper1q48vyzzz82kh9sn2zsslna3mhujx70s7yg5jzf","amount":{"denom":"ustars","amount":"1000000"}}}}]}}],"nonce":"1"}"#;
         let encoded_string = base64_url::encode(&json_str);
@@ -141,5 +143,130 @@ mod tests {
     }
 
 
+    #[test]
+    fn can_check_software_passkeys() {
+        use crate::passkey::authenticator::{AssertionOptions, SoftAuthenticator};
+
+        let deps = mock_dependencies();
+        let deps = deps.as_ref();
+
+        let mut authenticator = SoftAuthenticator::from_seed(b"saa", "http://localhost:5173").unwrap();
+        assert_eq!(authenticator.rp_id, "localhost");
+
+        let options = AssertionOptions::default();
+        let credential = authenticator.assert_message(b"123", &options).unwrap();
+        assert_eq!(credential.client_data.challenge, "MTIz");
+        assert!(credential.verify_cosmwasm(deps.api).is_ok());
+
+        // signature counter is a part of the signed data
+        let next = authenticator.assert_message(b"123", &options).unwrap();
+        assert_eq!(next.authenticator_data[32..], [0x05, 0, 0, 0, 1]);
+        assert!(next.verify_cosmwasm(deps.api).is_ok());
+
+        let mut tampered = credential.clone();
+        tampered.client_data.origin = "http://localhost:3000".into();
+        assert!(tampered.verify_cosmwasm(deps.api).is_err());
+
+        let mut tampered = credential;
+        tampered.authenticator_data = next.authenticator_data;
+        assert!(tampered.verify_cosmwasm(deps.api).is_err());
+    }
+
+
+    #[test]
+    fn software_passkeys_are_deterministic() {
+        use crate::passkey::authenticator::{AssertionOptions, SoftAuthenticator};
+
+        let options = AssertionOptions::default();
+        let first = SoftAuthenticator::from_seed(b"saa", "https://example.com")
+            .unwrap()
+            .assert_message(b"123", &options)
+            .unwrap();
+        let second = SoftAuthenticator::from_seed(b"saa", "https://example.com")
+            .unwrap()
+            .assert_message(b"123", &options)
+            .unwrap();
+        assert_eq!(first, second);
+
+        let other = SoftAuthenticator::from_seed(b"other", "https://example.com")
+            .unwrap()
+            .assert_message(b"123", &options)
+            .unwrap();
+        assert_ne!(first.pubkey, other.pubkey);
+        assert_ne!(first.id, other.id);
+    }
+
+
+    #[test]
+    fn can_check_software_passkeys_edge_cases() {
+        use crate::passkey::authenticator::{AssertionOptions, SoftAuthenticator};
+
+        let deps = mock_dependencies();
+        let deps = deps.as_ref();
+
+        let mut authenticator = SoftAuthenticator::from_seed(b"saa", "https://app.example.com:8080")
+            .unwrap()
+            .with_credential_id("passkey");
+        assert_eq!(authenticator.rp_id, "app.example.com");
+
+        let cross_origin = authenticator.assert_message(b"123", &AssertionOptions {
+            cross_origin: true,
+            other_keys: true,
+            ..Default::default()
+        }).unwrap();
+        assert!(cross_origin.client_data.cross_origin);
+        assert!(cross_origin.client_data.other_keys_can_be_added_here.is_some());
+        assert_eq!(cross_origin.id, "passkey");
+        assert!(cross_origin.verify_cosmwasm(deps.api).is_ok());
+
+        let not_verified = authenticator.assert_message(b"123", &AssertionOptions {
+            user_verified: false,
+            ..Default::default()
+        }).unwrap();
+        assert_eq!(not_verified.authenticator_data[32], 0x01);
+        assert!(not_verified.verify_cosmwasm(deps.api).is_ok());
+
+        let high_s = authenticator.assert_message(b"123", &AssertionOptions {
+            high_s: true,
+            ..Default::default()
+        }).unwrap();
+        let signature = p256::ecdsa::Signature::from_slice(&high_s.signature).unwrap();
+        assert!(signature.normalize_s().is_some());
+        assert!(high_s.verify_cosmwasm(deps.api).is_ok());
+    }
 
+
+    #[test]
+    fn passkey_origins_are_checked() {
+        use saa_common::{hashes::sha256, AuthError};
+        use crate::passkey::{authenticator::{AssertionOptions, SoftAuthenticator}, utils::rp_id_matches, PasskeyExtension};
+
+        assert!(rp_id_matches("https://login.example.com:8080", &sha256(b"example.com")));
+        assert!(!rp_id_matches("https://notexample.com", &sha256(b"example.com")));
+
+        let credential = SoftAuthenticator::from_seed(b"saa", "https://app.example.com")
+            .unwrap()
+            .assert_message(b"123", &AssertionOptions::default())
+            .unwrap();
+        assert!(credential.validate().is_ok());
+
+        let mut tampered = credential.clone();
+        tampered.client_data.origin = "https://evil.com".into();
+        assert_eq!(tampered.validate(), Err(AuthError::DigestMismatch));
+
+        let mut stored = PasskeyExtension {
+            origin: "https://app.example.com".into(),
+            pubkey: credential.pubkey.clone(),
+            cross_origin: false,
+            user_handle: None,
+        };
+        assert!(credential.assert_extension(&stored).is_ok());
+        assert_eq!(tampered.assert_extension(&stored), Err(AuthError::OriginMismatch));
+
+        let mut cross_origin = credential.clone();
+        cross_origin.client_data.cross_origin = true;
+        assert_eq!(cross_origin.assert_extension(&stored), Err(AuthError::OriginMismatch));
+        stored.cross_origin = true;
+        assert!(cross_origin.assert_extension(&stored).is_ok());
+    }
 }
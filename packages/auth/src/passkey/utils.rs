@@ -1,8 +1,31 @@
+use saa_common::hashes::sha256;
+
+
+/// Whether the hash of the relying party id at the start of the authenticator data belongs to the origin.
+/// The id can be the host of the origin or any of its parent domains
+pub fn rp_id_matches(origin: &str, rp_id_hash: &[u8]) -> bool {
+    let mut host = origin
+        .split("://").last().unwrap_or_default()
+        .split([':', '/']).next().unwrap_or_default();
+    loop {
+        if sha256(host.as_bytes()) == rp_id_hash {
+            return true;
+        }
+        match host.split_once('.') {
+            Some((_, parent)) => host = parent,
+            None => return false,
+        }
+    }
+}
+
+
+#[deprecated(note = "use `Binary::to_base64url` instead")]
 pub fn base64_to_url(b64: &str) -> String {
     b64.replace("+", "-").replace("/", "_").replace("=", "")
 }
 
 
+#[deprecated(note = "use `Binary::from_base64url` instead")]
 pub fn url_to_base64(url: &str) -> String {
     let mut b64 = url.replace("-", "+").replace("_", "/");
     let len = b64.len();
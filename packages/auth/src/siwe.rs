@@ -0,0 +1,7 @@
+mod message;
+mod siwe;
+pub use message::SiweMessage;
+pub use siwe::*;
+
+#[cfg(test)]
+mod tests;
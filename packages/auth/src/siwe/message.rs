@@ -0,0 +1,193 @@
+use saa_common::{ensure, format, AuthError, String, ToString, Vec};
+
+
+const PREAMBLE: &str = " wants you to sign in with your Ethereum account:";
+
+
+/// Fields of a Sign-In with Ethereum message as specified in EIP-4361
+#[derive(Clone, Debug, PartialEq)]
+pub struct SiweMessage {
+    pub scheme: Option<String>,
+    pub domain: String,
+    pub address: String,
+    pub statement: Option<String>,
+    pub uri: String,
+    pub version: String,
+    pub chain_id: u64,
+    pub nonce: String,
+    /// unix timestamps in seconds
+    pub issued_at: u64,
+    pub expiration_time: Option<u64>,
+    pub not_before: Option<u64>,
+    pub request_id: Option<String>,
+    pub resources: Vec<String>,
+}
+
+
+fn invalid(msg: &str) -> AuthError {
+    AuthError::InvalidData(format!("Invalid SIWE message: {}", msg))
+}
+
+
+/// Value of a `Key: value` line
+fn field<'a>(line: Option<&'a str>, key: &str) -> Result<&'a str, AuthError> {
+    line.and_then(|l| l.strip_prefix(key))
+        .and_then(|l| l.strip_prefix(": "))
+        .ok_or_else(|| invalid(&format!("missing '{}'", key)))
+}
+
+
+impl SiweMessage {
+
+    pub fn parse(message: &str) -> Result<Self, AuthError> {
+        let mut lines = message.split('\n').peekable();
+
+        let header = lines.next()
+            .and_then(|l| l.strip_suffix(PREAMBLE))
+            .ok_or_else(|| invalid("missing the preamble"))?;
+        let (scheme, domain) = match header.split_once("://") {
+            Some((scheme, domain)) => (Some(scheme.to_string()), domain),
+            None => (None, header),
+        };
+        let address = lines.next().ok_or_else(|| invalid("missing the address"))?;
+
+        // the statement is optional and surrounded by empty lines
+        while lines.peek() == Some(&"") {
+            lines.next();
+        }
+        let statement = match lines.peek() {
+            Some(line) if !line.starts_with("URI: ") => {
+                let statement = line.to_string();
+                lines.next();
+                ensure!(lines.next() == Some(""), invalid("missing the line after the statement"));
+                Some(statement)
+            },
+            _ => None,
+        };
+
+        let uri = field(lines.next(), "URI")?;
+        let version = field(lines.next(), "Version")?;
+        let chain_id = field(lines.next(), "Chain ID")?
+            .parse::<u64>()
+            .map_err(|_| invalid("chain id must be a number"))?;
+        let nonce = field(lines.next(), "Nonce")?;
+        let issued_at = parse_timestamp(field(lines.next(), "Issued At")?)?;
+
+        let mut optional = |key: &str| -> Result<Option<String>, AuthError> {
+            match lines.peek().filter(|l| l.starts_with(key)) {
+                Some(_) => Ok(Some(field(lines.next(), key)?.to_string())),
+                None => Ok(None),
+            }
+        };
+        let expiration_time = optional("Expiration Time")?;
+        let not_before = optional("Not Before")?;
+        let request_id = optional("Request ID")?;
+
+        let mut resources = Vec::new();
+        if lines.peek() == Some(&"Resources:") {
+            lines.next();
+            while let Some(resource) = lines.peek().and_then(|l| l.strip_prefix("- ")) {
+                resources.push(resource.to_string());
+                lines.next();
+            }
+        }
+        ensure!(lines.all(|l| l.is_empty()), invalid("unexpected lines at the end"));
+
+        let message = Self {
+            scheme,
+            domain: domain.to_string(),
+            address: address.to_string(),
+            statement,
+            uri: uri.to_string(),
+            version: version.to_string(),
+            chain_id,
+            nonce: nonce.to_string(),
+            issued_at,
+            expiration_time: expiration_time.as_deref().map(parse_timestamp).transpose()?,
+            not_before: not_before.as_deref().map(parse_timestamp).transpose()?,
+            request_id,
+            resources,
+        };
+        message.validate()?;
+        Ok(message)
+    }
+
+
+    fn validate(&self) -> Result<(), AuthError> {
+        ensure!(!self.domain.is_empty(), invalid("empty domain"));
+        ensure!(!self.uri.is_empty(), invalid("empty URI"));
+        ensure!(self.version == "1", invalid("version must be 1"));
+        ensure!(
+            self.address.len() == 42 && self.address.starts_with("0x")
+                && self.address[2..].chars().all(|c| c.is_ascii_hexdigit()),
+            invalid("address must be 0x followed by 20 hex encoded bytes")
+        );
+        ensure!(
+            self.nonce.len() >= 8 && self.nonce.chars().all(|c| c.is_ascii_alphanumeric()),
+            invalid("nonce must have at least 8 alphanumeric characters")
+        );
+        Ok(())
+    }
+}
+
+
+/// Seconds since the unix epoch of an RFC 3339 date-time e.g. `2021-09-30T16:25:24.000Z`.
+/// Fractions of a second are ignored
+pub fn parse_timestamp(value: &str) -> Result<u64, AuthError> {
+    let error = || invalid(&format!("'{}' is not an RFC 3339 date-time", value));
+    let number = |s: &str| -> Result<i64, AuthError> {
+        ensure!(!s.is_empty() && s.chars().all(|c| c.is_ascii_digit()), error());
+        s.parse::<i64>().map_err(|_| error())
+    };
+
+    ensure!(value.is_ascii() && value.len() >= 20, error());
+    let (date, time) = value.split_once(['T', 't']).ok_or_else(error)?;
+    let mut date = date.split('-');
+    let (year, month, day) = match (date.next(), date.next(), date.next(), date.next()) {
+        (Some(y), Some(m), Some(d), None) if y.len() == 4 && m.len() == 2 && d.len() == 2 => {
+            (number(y)?, number(m)?, number(d)?)
+        },
+        _ => return Err(error()),
+    };
+    ensure!((1..=12).contains(&month) && (1..=31).contains(&day), error());
+
+    let (clock, offset) = match time.find(['Z', 'z', '+', '-']) {
+        Some(i) => time.split_at(i),
+        None => return Err(error()),
+    };
+    let offset = match offset {
+        "Z" | "z" => 0,
+        _ => {
+            let (hours, minutes) = offset[1..].split_once(':').ok_or_else(error)?;
+            ensure!(hours.len() == 2 && minutes.len() == 2, error());
+            let seconds = number(hours)? * 3600 + number(minutes)? * 60;
+            if offset.starts_with('-') { -seconds } else { seconds }
+        }
+    };
+    let clock = clock.split_once('.').map(|(c, fraction)| {
+        number(fraction).map(|_| c)
+    }).unwrap_or(Ok(clock))?;
+    let mut clock = clock.split(':');
+    let (hour, minute, second) = match (clock.next(), clock.next(), clock.next(), clock.next()) {
+        (Some(h), Some(m), Some(s), None) if h.len() == 2 && m.len() == 2 && s.len() == 2 => {
+            (number(h)?, number(m)?, number(s)?)
+        },
+        _ => return Err(error()),
+    };
+    ensure!(hour < 24 && minute < 60 && second <= 60, error());
+
+    let seconds = days_from_civil(year, month, day) * 86_400 
+        + hour * 3600 + minute * 60 + second - offset;
+    u64::try_from(seconds).map_err(|_| error())
+}
+
+
+/// Days since the unix epoch of a date in the proleptic Gregorian calendar
+fn days_from_civil(year: i64, month: i64, day: i64) -> i64 {
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = year.div_euclid(400);
+    let year_of_era = year - era * 400;
+    let day_of_year = (153 * (month + if month > 2 { -3 } else { 9 }) + 2) / 5 + day - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+    era * 146_097 + day_of_era - 719_468
+}
@@ -0,0 +1,139 @@
+use saa_common::{
+    ensure, messages::MsgDataToVerify, AuthError, Binary, CredentialId, 
+    String, ToString, Verifiable
+};
+use saa_schema::wasm_serde;
+
+use crate::eth::EthPersonalSign;
+use super::SiweMessage;
+
+#[cfg(feature = "wasm")]
+use saa_common::cosmwasm::Env;
+
+
+/// Namespace of the CAIP-10 resource binding a message to a cosmos account
+const CAIP_NAMESPACE: &str = "cosmos:";
+
+
+#[wasm_serde]
+pub struct Siwe {
+    /// EIP-4361 text message signed with `personal_sign`
+    pub message: String,
+    /// Signature returned by `personal_sign` encoded as hex with an optional `0x` prefix
+    #[cfg_attr(any(feature = "cosmwasm", feature = "wasm"),
+        serde(with = "saa_common::as_hex"),
+        schemars(with = "String")
+    )]
+    pub signature: Binary,
+}
+
+
+/// Parts of the first message stored during the registration that later messages must repeat
+#[cfg_attr(not(feature = "wasm"), derive(
+    ::saa_schema::serde::Serialize,
+    ::saa_schema::serde::Deserialize,
+))]
+#[wasm_serde]
+pub struct SiweExtension {
+    pub domain: String,
+    pub uri: String,
+    pub chain_id: u64,
+}
+
+
+impl Siwe {
+
+    pub fn parsed(&self) -> Result<SiweMessage, AuthError> {
+        SiweMessage::parse(&self.message)
+    }
+
+    pub fn extension(&self) -> Result<SiweExtension, AuthError> {
+        let parsed = self.parsed()?;
+        Ok(SiweExtension {
+            domain: parsed.domain,
+            uri: parsed.uri,
+            chain_id: parsed.chain_id,
+        })
+    }
+
+    /// Check that the message has been created by the same application as the stored one
+    pub fn assert_extension(&self, stored: &SiweExtension) -> Result<(), AuthError> {
+        let parsed = self.parsed()?;
+        ensure!(parsed.domain == stored.domain && parsed.uri == stored.uri, AuthError::OriginMismatch);
+        ensure!(parsed.chain_id == stored.chain_id, AuthError::ChainIdMismatch);
+        Ok(())
+    }
+
+    /// Chain, contract and nonce that the message is bound to. The contract is listed in the resources
+    /// as a CAIP-10 account id e.g. `cosmos:pion-1:neutron1...` and the nonce is the decimal account
+    /// number padded with zeros to the 8 characters required by the specification. The request id
+    /// if present is the id of an account hosted by a shared contract
+    pub fn replay_data(&self) -> Result<MsgDataToVerify, AuthError> {
+        let parsed = self.parsed()?;
+        let (chain_id, contract_address) = parsed.resources.iter()
+            .find_map(|r| r.strip_prefix(CAIP_NAMESPACE).and_then(|r| r.split_once(':')))
+            .ok_or_else(|| AuthError::MissingData("SIWE resource with the account".to_string()))?;
+        let nonce = parsed.nonce.parse::<u128>()
+            .map_err(|_| AuthError::InvalidData("SIWE nonce must be the account number".to_string()))?;
+        Ok(MsgDataToVerify {
+            chain_id: chain_id.to_string(),
+            contract_address: contract_address.to_string(),
+            nonce: nonce.to_string(),
+            account_id: parsed.request_id,
+        })
+    }
+
+    /// Check the validity period of the message against the current block
+    #[cfg(feature = "wasm")]
+    pub fn validate_time(&self, env: &Env) -> Result<(), AuthError> {
+        let parsed = self.parsed()?;
+        let now = env.block.time.seconds();
+        ensure!(
+            parsed.issued_at <= now && parsed.not_before.is_none_or(|t| t <= now),
+            AuthError::InvalidData("SIWE message is not valid yet".to_string())
+        );
+        ensure!(parsed.expiration_time.is_none_or(|t| now < t), AuthError::Expired);
+        Ok(())
+    }
+
+    fn personal_sign(&self) -> Result<EthPersonalSign, AuthError> {
+        Ok(EthPersonalSign {
+            message: Binary::from(self.message.as_bytes()),
+            signature: self.signature.clone(),
+            signer: self.parsed()?.address,
+        })
+    }
+}
+
+
+impl Verifiable for Siwe {
+
+    /// Lowercase address of the signer
+    fn id(&self) -> CredentialId {
+        self.parsed()
+            .map(|m| m.address.to_lowercase().into_bytes())
+            .unwrap_or_default()
+    }
+
+    fn hrp(&self) -> Option<String> {
+        #[cfg(feature = "injective")]
+        {
+            return Some("inj".to_string());
+        }
+        None
+    }
+
+    fn validate(&self) -> Result<(), AuthError> {
+        self.personal_sign()?.validate()
+    }
+
+    #[cfg(feature = "native")]
+    fn verify(&self) -> Result<(), AuthError> {
+        self.personal_sign()?.verify()
+    }
+
+    #[cfg(feature = "wasm")]
+    fn verify_cosmwasm(&self, api: &dyn saa_common::cosmwasm::Api) -> Result<(), AuthError> {
+        self.personal_sign()?.verify_cosmwasm(api)
+    }
+}
@@ -0,0 +1,103 @@
+use saa_common::AuthError;
+
+use super::{message::parse_timestamp, Siwe, SiweMessage};
+
+
+const MESSAGE: &str = "example.com wants you to sign in with your Ethereum account:
+0xC02aaA39b223FE8D0A0e5C4F27eAD9083C756Cc2
+
+I accept the ExampleOrg Terms of Service: https://example.com/tos
+
+URI: https://example.com/login
+Version: 1
+Chain ID: 1
+Nonce: 00000007
+Issued At: 2021-09-30T16:25:24Z
+Expiration Time: 2021-10-01T16:25:24.000Z
+Resources:
+- ipfs://bafybeiemxf5abjwjbikoz4mc3a3dla6ual3jsgpdr4cjr3oz3evfyavhwq/
+- cosmos:pion-1:neutron1contract";
+
+
+#[test]
+fn parses_all_fields() {
+    let parsed = SiweMessage::parse(MESSAGE).unwrap();
+    assert_eq!(parsed.scheme, None);
+    assert_eq!(parsed.domain, "example.com");
+    assert_eq!(parsed.address, "0xC02aaA39b223FE8D0A0e5C4F27eAD9083C756Cc2");
+    assert_eq!(parsed.statement.as_deref(), Some("I accept the ExampleOrg Terms of Service: https://example.com/tos"));
+    assert_eq!(parsed.uri, "https://example.com/login");
+    assert_eq!(parsed.chain_id, 1);
+    assert_eq!(parsed.issued_at, 1633019124);
+    assert_eq!(parsed.expiration_time, Some(1633019124 + 86_400));
+    assert_eq!(parsed.not_before, None);
+    assert_eq!(parsed.resources.len(), 2);
+
+    let without_statement = MESSAGE.replace(
+        "I accept the ExampleOrg Terms of Service: https://example.com/tos\n\n", ""
+    );
+    let parsed = SiweMessage::parse(&format!("https://{}", without_statement)).unwrap();
+    assert_eq!(parsed.scheme.as_deref(), Some("https"));
+    assert_eq!(parsed.statement, None);
+}
+
+
+#[test]
+fn replay_data_from_resources_and_nonce() {
+    let siwe = Siwe { message: MESSAGE.to_string(), signature: Default::default() };
+    let data = siwe.replay_data().unwrap();
+    assert_eq!(data.chain_id, "pion-1");
+    assert_eq!(data.contract_address, "neutron1contract");
+    assert_eq!(data.nonce, "7");
+    assert_eq!(data.account_id, None);
+
+    let namespaced = MESSAGE.replace("\nResources:", "\nRequest ID: alice\nResources:");
+    let data = Siwe { message: namespaced, ..siwe.clone() }.replay_data().unwrap();
+    assert_eq!(data.account_id.as_deref(), Some("alice"));
+
+    let siwe = Siwe { message: MESSAGE.replace("- cosmos:pion-1:neutron1contract", ""), ..siwe };
+    assert!(matches!(siwe.replay_data(), Err(AuthError::MissingData(_))));
+}
+
+
+#[test]
+fn invalid_messages_are_rejected() {
+    for (from, to) in [
+        (" wants you to sign in", " wants to sign in"),
+        ("0xC02aaA39b223FE8D0A0e5C4F27eAD9083C756Cc2", "0xC02aaA39"),
+        ("Version: 1", "Version: 2"),
+        ("Chain ID: 1", "Chain ID: one"),
+        ("Nonce: 00000007", "Nonce: 7"),
+        ("Issued At: 2021-09-30T16:25:24Z", "Issued At: 2021-09-30"),
+        ("\nURI: https://example.com/login", ""),
+        ("- cosmos:pion-1:neutron1contract", "- cosmos:pion-1:neutron1contract\nextra"),
+    ] {
+        let message = MESSAGE.replace(from, to);
+        assert!(
+            matches!(SiweMessage::parse(&message), Err(AuthError::InvalidData(_))), 
+            "accepted after replacing '{from}'"
+        );
+    }
+}
+
+
+#[test]
+fn timestamps_with_offsets() {
+    assert_eq!(parse_timestamp("1970-01-01T00:00:00Z").unwrap(), 0);
+    assert_eq!(parse_timestamp("2024-02-29T23:59:59.999+02:30").unwrap(), 1709242199);
+    assert_eq!(parse_timestamp("2021-09-30T18:25:24+02:00").unwrap(), 1633019124);
+    assert!(parse_timestamp("1969-12-31T23:59:59Z").is_err());
+    assert!(parse_timestamp("2021-13-01T00:00:00Z").is_err());
+    assert!(parse_timestamp("2021-09-30T16:25:24").is_err());
+}
+
+
+#[test]
+fn signatures_are_hex_encoded() {
+    let siwe : Siwe = saa_common::from_json(r#"{"message":"hi","signature":"0x01ff"}"#).unwrap();
+    assert_eq!(siwe.signature.to_vec(), vec![1, 255]);
+    assert_eq!(
+        saa_common::to_json_binary(&siwe).unwrap().to_vec(),
+        br#"{"message":"hi","signature":"01ff"}"#.to_vec()
+    );
+}
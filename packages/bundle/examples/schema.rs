@@ -0,0 +1,24 @@
+//! Writes the JSON schemas of the shared types to `schema/` and regenerates
+//! the TypeScript definitions of the `smart-account-auth` package
+//!
+//! cargo run -p smart-account-auth --example schema --features typescript,curves,spending,custom,siwe
+
+use std::{fs, path::Path};
+use smart_account_auth::schema::{schemas, typescript};
+
+
+fn main() -> std::io::Result<()> {
+    let root = Path::new(env!("CARGO_MANIFEST_DIR"));
+
+    let dir = root.join("schema");
+    fs::create_dir_all(&dir)?;
+    for (name, schema) in schemas() {
+        let json = serde_json::to_string_pretty(&schema).expect("serializable schema");
+        fs::write(dir.join(format!("{name}.json")), json + "\n")?;
+    }
+
+    let ts = root.join("../../package/src/generated.ts");
+    fs::write(&ts, typescript())?;
+    println!("Wrote {} schemas to {} and {}", schemas().len(), dir.display(), ts.display());
+    Ok(())
+}
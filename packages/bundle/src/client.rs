@@ -0,0 +1,287 @@
+//! Signing of credentials with local private keys for backend services and tests
+#![allow(unreachable_code)]
+
+use k256::ecdsa::signature::hazmat::PrehashSigner;
+use saa_common::{
+    hashes::keccak256, to_json_binary, utils::pubkey_to_address,
+    messages::{AuthPayload, SignedDataMsg},
+    AuthError, Binary, Verifiable,
+};
+use serde::Serialize;
+
+#[cfg(any(feature = "cosmos", feature = "curves", feature = "ed25519"))]
+use saa_common::hashes::sha256;
+
+use crate::Credential;
+
+
+// Serializable copies of the library types that derive serde only in wasm environments
+
+/// Same as `MsgDataToSign` with fields in the alphabetical order
+#[derive(Serialize)]
+struct DataToSign<'a, M> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    account_id: Option<&'a str>,
+    chain_id: &'a str,
+    contract_address: &'a str,
+    messages: Vec<M>,
+    nonce: String,
+}
+
+/// Same as `PasskeyPayload`
+#[cfg(feature = "passkeys")]
+#[derive(Serialize)]
+struct PasskeyPayload {
+    authenticator_data: Binary,
+    pubkey: Option<Binary>,
+    other_keys: Option<bool>,
+}
+
+
+/// Message in the format of `MsgDataToSign` expected by the replay protection of the contracts
+pub fn data_to_sign<M: Serialize>(
+    chain_id: &str,
+    contract_address: &str,
+    messages: Vec<M>,
+    nonce: u128,
+) -> Result<Binary, AuthError> {
+    to_json_binary(&DataToSign {
+        account_id: None,
+        chain_id,
+        contract_address,
+        messages,
+        nonce: nonce.to_string(),
+    })
+}
+
+
+/// Same as [`data_to_sign`] for an account hosted by a shared contract in a namespaced storage
+pub fn account_data_to_sign<M: Serialize>(
+    chain_id: &str,
+    contract_address: &str,
+    account_id: &str,
+    messages: Vec<M>,
+    nonce: u128,
+) -> Result<Binary, AuthError> {
+    to_json_binary(&DataToSign {
+        account_id: Some(account_id),
+        chain_id,
+        contract_address,
+        messages,
+        nonce: nonce.to_string(),
+    })
+}
+
+
+/// Message of a signed credential together with the payload needed to load it from storage
+pub fn signed_data_msg(credential: &Credential) -> Result<SignedDataMsg, AuthError> {
+    let (signature, extension) = match credential {
+        Credential::Caller(_) => return Err(AuthError::UnsupportedCredential("caller".to_string())),
+        #[cfg(feature = "ethereum")]
+        Credential::EthPersonalSign(c) => (c.signature.clone(), None),
+        #[cfg(feature = "cosmos")]
+        Credential::CosmosArbitrary(c) => (c.signature.clone(), None),
+        #[cfg(feature = "passkeys")]
+        Credential::Passkey(c) => (c.signature.clone(), Some(to_json_binary(&PasskeyPayload {
+            authenticator_data: c.authenticator_data.clone(),
+            pubkey: c.pubkey.clone(),
+            other_keys: Some(c.client_data.other_keys_can_be_added_here.is_some()),
+        })?)),
+        #[cfg(all(not(feature = "curves"), feature = "ed25519"))]
+        Credential::Ed25519(c) => (c.signature.clone(), None),
+        #[cfg(feature = "curves")]
+        Credential::Secp256k1(c) => (c.signature.clone(), None),
+        #[cfg(feature = "curves")]
+        Credential::Secp256r1(c) => (c.signature.clone(), None),
+        #[cfg(feature = "curves")]
+        Credential::Ed25519(c) => (c.signature.clone(), None),
+        #[cfg(feature = "custom")]
+        Credential::Custom(c) => (c.signature.clone(), c.payload.clone()),
+        #[cfg(feature = "siwe")]
+        Credential::Siwe(c) => (c.signature.clone(), None),
+    };
+    Ok(SignedDataMsg {
+        data: credential.message().into(),
+        signature,
+        payload: Some(AuthPayload {
+            credential_id: Some(credential.id()),
+            hrp: credential.hrp(),
+            address: None,
+            extension,
+        }),
+    })
+}
+
+
+/// Local secp256k1 key for Ethereum, Cosmos and raw curve credentials
+pub struct Secp256k1Key(k256::ecdsa::SigningKey);
+
+
+impl Secp256k1Key {
+
+    pub fn from_bytes(secret: &[u8]) -> Result<Self, AuthError> {
+        k256::ecdsa::SigningKey::from_slice(secret)
+            .map(Self)
+            .map_err(|e| AuthError::Crypto(e.to_string()))
+    }
+
+    /// Compressed public key
+    pub fn public_key(&self) -> Binary {
+        self.0.verifying_key().to_encoded_point(true).as_bytes().to_vec().into()
+    }
+
+    /// Lowercase hex encoded Ethereum address prefixed with `0x`
+    pub fn eth_address(&self) -> String {
+        let point = self.0.verifying_key().to_encoded_point(false);
+        let hash = keccak256(&point.as_bytes()[1..]);
+        format!("0x{}", hex::encode(&hash[12..]))
+    }
+
+    pub fn cosmos_address(&self, hrp: &str) -> Result<String, AuthError> {
+        pubkey_to_address(&self.public_key(), hrp)
+    }
+
+    /// Sign an already hashed message
+    pub fn sign_digest(&self, digest: &[u8]) -> Result<Binary, AuthError> {
+        let signature : k256::ecdsa::Signature = self.0
+            .sign_prehash(digest)
+            .map_err(|e| AuthError::Crypto(e.to_string()))?;
+        Ok(signature.to_bytes().to_vec().into())
+    }
+
+    /// Message signed with `personal_sign` as specified in EIP-191
+    #[cfg(feature = "ethereum")]
+    pub fn eth_personal_sign(&self, message: impl Into<Binary>) -> Result<Credential, AuthError> {
+        let message : Binary = message.into();
+        let digest = saa_auth::eth::utils::preamble_msg_eth(&message);
+        let (signature, recovery) : (k256::ecdsa::Signature, k256::ecdsa::RecoveryId) = self.0
+            .sign_prehash(&digest)
+            .map_err(|e| AuthError::Crypto(e.to_string()))?;
+        let mut signature = signature.to_bytes().to_vec();
+        signature.push(27 + recovery.to_byte());
+
+        Ok(Credential::EthPersonalSign(saa_auth::eth::EthPersonalSign {
+            message,
+            signature: signature.into(),
+            signer: self.eth_address(),
+        }))
+    }
+
+    /// Sign-In with Ethereum message signed with `personal_sign`
+    #[cfg(feature = "siwe")]
+    pub fn siwe(&self, message: &str) -> Result<Credential, AuthError> {
+        let Credential::EthPersonalSign(signed) = self.eth_personal_sign(message.as_bytes())? else {
+            unreachable!()
+        };
+        Ok(Credential::Siwe(saa_auth::siwe::Siwe {
+            message: message.to_string(),
+            signature: signed.signature,
+        }))
+    }
+
+    /// Message signed as ADR-036 arbitrary data by the address with the given prefix
+    #[cfg(feature = "cosmos")]
+    pub fn cosmos_arbitrary(&self, message: impl Into<Binary>, hrp: &str) -> Result<Credential, AuthError> {
+        let message : Binary = message.into();
+        let preamble = saa_auth::cosmos::utils::sign_doc_arb_036(&self.cosmos_address(hrp)?, &message);
+        Ok(Credential::CosmosArbitrary(saa_auth::cosmos::CosmosArbitrary {
+            signature: self.sign_digest(&sha256(preamble.as_bytes()))?,
+            pubkey: self.public_key(),
+            hrp: Some(hrp.to_string()),
+            message,
+        }))
+    }
+
+    /// Sha256 hash of the message signed directly
+    #[cfg(feature = "curves")]
+    pub fn secp256k1(&self, message: impl Into<Binary>, hrp: Option<String>) -> Result<Credential, AuthError> {
+        let message : Binary = message.into();
+        Ok(Credential::Secp256k1(saa_curves::secp256k1::Secp256k1 {
+            signature: self.sign_digest(&sha256(&message))?,
+            pubkey: self.public_key(),
+            message,
+            hrp,
+        }))
+    }
+}
+
+
+/// Local secp256r1 key for raw curve and passkey credentials
+pub struct Secp256r1Key(p256::ecdsa::SigningKey);
+
+
+impl Secp256r1Key {
+
+    pub fn from_bytes(secret: &[u8]) -> Result<Self, AuthError> {
+        p256::ecdsa::SigningKey::from_slice(secret)
+            .map(Self)
+            .map_err(|e| AuthError::Crypto(e.to_string()))
+    }
+
+    /// Compressed public key
+    pub fn public_key(&self) -> Binary {
+        self.0.verifying_key().to_encoded_point(true).as_bytes().to_vec().into()
+    }
+
+    /// Sign an already hashed message
+    pub fn sign_digest(&self, digest: &[u8]) -> Result<Binary, AuthError> {
+        let signature : p256::ecdsa::Signature = self.0
+            .sign_prehash(digest)
+            .map_err(|e| AuthError::Crypto(e.to_string()))?;
+        Ok(signature.to_bytes().to_vec().into())
+    }
+
+    /// Sha256 hash of the message signed directly
+    #[cfg(feature = "curves")]
+    pub fn secp256r1(&self, message: impl Into<Binary>) -> Result<Credential, AuthError> {
+        let message : Binary = message.into();
+        Ok(Credential::Secp256r1(saa_curves::secp256r1::Secp256r1 {
+            signature: self.sign_digest(&sha256(&message))?,
+            pubkey: self.public_key(),
+            message,
+        }))
+    }
+
+    /// Assertion of a synthetic passkey with the given id made for the given origin.
+    /// The message is used as the challenge in the same way as the browser helpers do
+    #[cfg(feature = "passkeys")]
+    pub fn passkey(&self, message: impl Into<Binary>, id: &str, origin: &str) -> Result<Credential, AuthError> {
+        use saa_auth::passkey::authenticator::{AssertionOptions, SoftAuthenticator};
+        let message : Binary = message.into();
+        let credential = SoftAuthenticator::from_secret(&self.0.to_bytes(), origin)?
+            .with_credential_id(id)
+            .assert_message(&message, &AssertionOptions::default())?;
+        Ok(Credential::Passkey(credential))
+    }
+}
+
+
+/// Local ed25519 key for raw curve credentials
+pub struct Ed25519Key(ed25519_zebra::SigningKey);
+
+
+impl Ed25519Key {
+
+    pub fn from_bytes(secret: &[u8]) -> Result<Self, AuthError> {
+        ed25519_zebra::SigningKey::try_from(secret)
+            .map(Self)
+            .map_err(|e| AuthError::Crypto(e.to_string()))
+    }
+
+    pub fn public_key(&self) -> Binary {
+        let key : [u8; 32] = ed25519_zebra::VerificationKey::from(&self.0).into();
+        key.to_vec().into()
+    }
+
+    /// Sha256 hash of the message signed directly
+    #[cfg(any(feature = "curves", feature = "ed25519"))]
+    pub fn ed25519(&self, message: impl Into<Binary>) -> Result<Credential, AuthError> {
+        let message : Binary = message.into();
+        let signature : [u8; 64] = self.0.sign(&sha256(&message)).into();
+        Ok(Credential::Ed25519(saa_curves::ed25519::Ed25519 {
+            signature: signature.to_vec().into(),
+            pubkey: self.public_key(),
+            message,
+        }))
+    }
+}
@@ -0,0 +1,141 @@
+//! Rebuilding the stored credentials from the parts of the signed messages
+
+use saa_common::{ensure, AuthError, Binary, CredentialId, String, ToString};
+
+
+/// Data of a signed message together with the extension stored for the credential
+pub(crate) struct CredentialParts {
+    pub id: CredentialId,
+    pub message: Binary,
+    pub signature: Binary,
+    pub hrp: Option<String>,
+    pub stored_extension: Option<Binary>,
+    pub passed_extension: Option<Binary>,
+}
+
+
+pub(crate) fn caller(parts: CredentialParts) -> Result<saa_auth::caller::Caller, AuthError> {
+    Ok(saa_auth::caller::Caller { id: parts.id })
+}
+
+
+#[cfg(feature = "ethereum")]
+pub(crate) fn eth_personal_sign(parts: CredentialParts) -> Result<saa_auth::eth::EthPersonalSign, AuthError> {
+    Ok(saa_auth::eth::EthPersonalSign {
+        message: parts.message,
+        signature: parts.signature,
+        signer: String::from_utf8(parts.id)?,
+    })
+}
+
+
+#[cfg(feature = "cosmos")]
+pub(crate) fn cosmos_arbitrary(parts: CredentialParts) -> Result<saa_auth::cosmos::CosmosArbitrary, AuthError> {
+    Ok(saa_auth::cosmos::CosmosArbitrary {
+        pubkey: Binary::new(parts.id),
+        message: parts.message,
+        signature: parts.signature,
+        hrp: parts.hrp,
+    })
+}
+
+
+#[cfg(feature = "passkeys")]
+pub(crate) fn passkey(parts: CredentialParts) -> Result<saa_auth::passkey::PasskeyCredential, AuthError> {
+    use saa_auth::passkey::*;
+    use saa_common::from_json;
+
+    ensure!(
+        parts.passed_extension.is_some(),
+        AuthError::MissingData("Payload must be provided for 'passkey'".to_string())
+    );
+    ensure!(
+        parts.stored_extension.is_some(),
+        AuthError::MissingData("Extension must be stored for 'passkey'".to_string())
+    );
+    let payload_ext : PasskeyPayload = from_json(parts.passed_extension.unwrap())?;
+    let stored_ext : PasskeyExtension = from_json(parts.stored_extension.unwrap())?;
+    let pubkey = payload_ext.pubkey.or(stored_ext.pubkey);
+    ensure!(
+        pubkey.is_some(),
+        AuthError::MissingData("No public key provided for 'passkey' credential".to_string())
+    );
+    let client_data = ClientData::new(
+        "webauthn.get".into(),
+        parts.message.to_base64url(),
+        stored_ext.origin,
+        stored_ext.cross_origin,
+        payload_ext.other_keys.unwrap_or_default()
+    );
+    Ok(PasskeyCredential {
+        id: String::from_utf8(parts.id)?,
+        pubkey,
+        signature: parts.signature,
+        client_data,
+        authenticator_data: payload_ext.authenticator_data,
+        user_handle: stored_ext.user_handle,
+    })
+}
+
+
+#[cfg(feature = "curves")]
+pub(crate) fn secp256k1(parts: CredentialParts) -> Result<saa_curves::secp256k1::Secp256k1, AuthError> {
+    Ok(saa_curves::secp256k1::Secp256k1 {
+        pubkey: Binary::new(parts.id),
+        signature: parts.signature,
+        message: parts.message,
+        hrp: parts.hrp,
+    })
+}
+
+
+#[cfg(feature = "curves")]
+pub(crate) fn secp256r1(parts: CredentialParts) -> Result<saa_curves::secp256r1::Secp256r1, AuthError> {
+    Ok(saa_curves::secp256r1::Secp256r1 {
+        pubkey: Binary::new(parts.id),
+        signature: parts.signature,
+        message: parts.message,
+    })
+}
+
+
+#[cfg(any(feature = "curves", feature = "ed25519"))]
+pub(crate) fn ed25519(parts: CredentialParts) -> Result<saa_curves::ed25519::Ed25519, AuthError> {
+    Ok(saa_curves::ed25519::Ed25519 {
+        pubkey: Binary::new(parts.id),
+        signature: parts.signature,
+        message: parts.message,
+    })
+}
+
+
+#[cfg(feature = "siwe")]
+pub(crate) fn siwe(parts: CredentialParts) -> Result<saa_auth::siwe::Siwe, AuthError> {
+    use saa_common::Verifiable;
+    let credential = saa_auth::siwe::Siwe {
+        message: String::from_utf8(parts.message.to_vec())?,
+        signature: parts.signature,
+    };
+    // the message must be signed by the address of the loaded credential
+    ensure!(
+        credential.id() == parts.id,
+        AuthError::InvalidData("Address of the message doesn't match the credential".to_string())
+    );
+    Ok(credential)
+}
+
+
+#[cfg(feature = "custom")]
+pub(crate) fn custom(parts: CredentialParts) -> Result<saa_auth::custom::CustomCredential, AuthError> {
+    ensure!(
+        parts.stored_extension.is_some(),
+        AuthError::MissingData("Extension must be stored for 'custom'".to_string())
+    );
+    Ok(saa_auth::custom::CustomCredential {
+        name: String::from_utf8(parts.stored_extension.unwrap().to_vec())?,
+        id: Binary::new(parts.id),
+        message: parts.message,
+        signature: parts.signature,
+        payload: parts.passed_extension,
+    })
+}
@@ -0,0 +1,40 @@
+//! Handlers of the messages that `#[saa_execute]` and `#[saa_query]` add to the contracts
+
+use saa_common::{
+    AuthError, 
+    cosmwasm::{Api, CosmosMsg, Env, Event, Storage},
+    messages::SignedDataMsg,
+    storage::ACCOUNT_NUMBER,
+};
+use serde::de::DeserializeOwned;
+
+use crate::Verifiers;
+
+
+/// Check that the signed data authorises exactly the given messages, verify it and consume the nonce.
+/// With `spending` feature the funds of the messages are checked against the limits of the credential.
+/// Returns the events to attach to the response
+pub fn execute_signed<T>(
+    api: &dyn Api,
+    storage: &mut dyn Storage,
+    env: &Env,
+    signed: SignedDataMsg,
+    msgs: &[CosmosMsg<T>],
+    verifiers: Option<Verifiers>,
+) -> Result<Vec<Event>, AuthError> 
+    where T: DeserializeOwned + PartialEq
+{
+    #[cfg(feature = "spending")]
+    return crate::functions::verify_cosmos_msgs(api, storage, env, signed, msgs, verifiers);
+    #[cfg(not(feature = "spending"))]
+    {
+        crate::functions::assert_messages(&signed.data, msgs)?;
+        crate::functions::verify_actions(api, storage, env, signed, verifiers)
+    }
+}
+
+
+/// Nonce that the next signed message must use
+pub fn account_number(storage: &dyn Storage) -> Result<u128, AuthError> {
+    Ok(ACCOUNT_NUMBER.may_load(storage)?.unwrap_or_default())
+}
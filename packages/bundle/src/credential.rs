@@ -1,7 +1,7 @@
 #![allow(unreachable_code)]
-use saa_common::{to_json_binary, AuthError, Binary, CredentialId, CredentialInfo, CredentialName, Verifiable};
+use saa_common::{to_json_binary, AuthError, Binary, CredentialInfo, Verifiable};
 use saa_auth::caller::Caller;
-use saa_schema::wasm_serde;
+use saa_schema::{wasm_serde, CredentialDispatch};
 
 #[cfg(feature = "curves")]
 use saa_curves::{ed25519::Ed25519, secp256k1::Secp256k1, secp256r1::Secp256r1};
@@ -18,110 +18,75 @@ use saa_auth::eth::EthPersonalSign;
 #[cfg(feature = "cosmos")]
 use saa_auth::cosmos::CosmosArbitrary;
 
+#[cfg(feature = "custom")]
+use saa_auth::custom::CustomCredential;
+
+#[cfg(feature = "siwe")]
+use saa_auth::siwe::Siwe;
+
 #[cfg(feature = "wasm")]
-use saa_common::cosmwasm::{Api, Addr, Env, MessageInfo};
+use saa_common::{CredentialName, cosmwasm::{Api, Addr, Env, MessageInfo}};
 
 #[cfg(all(feature = "wasm", feature = "storage"))]
-use saa_common::{storage::*, cosmwasm::Storage, messages::*, ensure, from_json};
+use {
+    saa_common::{storage::*, cosmwasm::Storage, messages::*, ensure, from_json},
+    crate::Verifiers,
+};
 
 
 #[wasm_serde]
+#[derive(CredentialDispatch)]
+#[credential(parts = "crate::construct::CredentialParts")]
 pub enum Credential {
+    #[credential(no_message, construct = "crate::construct::caller")]
     Caller(Caller),
 
     #[cfg(feature = "ethereum")]
+    #[credential(construct = "crate::construct::eth_personal_sign")]
     EthPersonalSign(EthPersonalSign),
 
     #[cfg(feature = "cosmos")]
+    #[credential(construct = "crate::construct::cosmos_arbitrary")]
     CosmosArbitrary(CosmosArbitrary),
 
     #[cfg(feature = "passkeys")]
+    #[credential(message = "passkey_message", construct = "crate::construct::passkey")]
     Passkey(PasskeyCredential),
 
     #[cfg(feature = "curves")]
+    #[credential(construct = "crate::construct::secp256k1")]
     Secp256k1(Secp256k1),
 
     #[cfg(feature = "curves")]
+    #[credential(construct = "crate::construct::secp256r1")]
     Secp256r1(Secp256r1),
     
     #[cfg(any(feature = "curves", feature = "ed25519" ))]
+    #[credential(construct = "crate::construct::ed25519")]
     Ed25519(Ed25519),
+
+    #[cfg(feature = "custom")]
+    #[credential(construct = "crate::construct::custom")]
+    Custom(CustomCredential),
+
+    #[cfg(feature = "siwe")]
+    #[credential(message = "siwe_message", construct = "crate::construct::siwe")]
+    Siwe(Siwe),
 }
 
 
-impl Credential {
+#[cfg(feature = "passkeys")]
+fn passkey_message(c: &PasskeyCredential) -> Vec<u8> {
+    Binary::from_base64url(&c.client_data.challenge).unwrap().to_vec()
+}
 
-    pub fn name(&self) -> CredentialName {
-        match self {
-            Credential::Caller(_) => CredentialName::Caller,
-            #[cfg(feature = "passkeys")]
-            Credential::Passkey(_) => CredentialName::Passkey,
-            #[cfg(feature = "ethereum")]
-            Credential::EthPersonalSign(_) => CredentialName::EthPersonalSign,
-            #[cfg(feature = "cosmos")]
-            Credential::CosmosArbitrary(_) => CredentialName::CosmosArbitrary,
-            #[cfg(all(not(feature = "curves"), feature = "ed25519"))]
-            Credential::Ed25519(_) => CredentialName::Ed25519,
-            #[cfg(feature = "curves")]
-            curve => {
-                match curve {
-                    Credential::Secp256k1(_) => CredentialName::Secp256k1,
-                    Credential::Secp256r1(_) => CredentialName::Secp256r1,
-                    Credential::Ed25519(_) => CredentialName::Ed25519,
-                    _ => unreachable!(),
-                }
-            },
-        }
-    }
+#[cfg(feature = "siwe")]
+fn siwe_message(c: &Siwe) -> Vec<u8> {
+    c.message.as_bytes().to_vec()
+}
 
-    fn value(&self) -> &dyn Verifiable {
-        match self {
-            Credential::Caller(c) => c,
-            #[cfg(feature = "passkeys")]
-            Credential::Passkey(c) => c,
-            #[cfg(feature = "ethereum")]
-            Credential::EthPersonalSign(c) => c,
-            #[cfg(feature = "cosmos")]
-            Credential::CosmosArbitrary(c) => c,
-            #[cfg(all(not(feature = "curves"), feature = "ed25519"))]
-            Credential::Ed25519(c) => c,
-            #[cfg(feature = "curves")]
-            curve => {
-                match curve {
-                    Credential::Secp256k1(c) => c,
-                    Credential::Secp256r1(c) => c,
-                    Credential::Ed25519(c) => c,
-                    _ => unreachable!(),
-                }
-            },
-        }
-    }
 
-    pub fn message(&self) -> Vec<u8> {
-        match self {
-            Credential::Caller(_) => Vec::new(),
-            #[cfg(feature = "ethereum")]
-            Credential::EthPersonalSign(c) => c.message.to_vec(),
-            #[cfg(feature = "cosmos")]
-            Credential::CosmosArbitrary(c) => c.message.to_vec(),
-            #[cfg(feature = "passkeys")]
-            Credential::Passkey(c) => {
-                let base64 =  saa_auth::passkey::utils::url_to_base64(&c.client_data.challenge);
-                Binary::from_base64(&base64).unwrap().to_vec()
-            },
-            #[cfg(all(not(feature = "curves"), feature = "ed25519"))]
-            Credential::Ed25519(c) => c.message.to_vec(),
-            #[cfg(feature = "curves")]
-            curve => {
-                match curve {
-                    Credential::Secp256k1(c) => c.message.to_vec(),
-                    Credential::Secp256r1(c) => c.message.to_vec(),
-                    Credential::Ed25519(c) => c.message.to_vec(),
-                    _ => unreachable!(),
-                }
-            },
-        }
-    }
+impl Credential {
 
     pub fn extension(&self) -> Result<Option<Binary>, AuthError> {
         #[cfg(feature = "passkeys")]
@@ -134,6 +99,15 @@ impl Credential {
                 user_handle: c.user_handle.clone(),
             })?));
         }
+        #[cfg(feature = "custom")]
+        if let Credential::Custom(c) = self {
+            // the tag of the verifier is needed to reconstruct the credential later
+            return Ok(Some(Binary::from(c.name.as_bytes())));
+        }
+        #[cfg(feature = "siwe")]
+        if let Credential::Siwe(c) = self {
+            return Ok(Some(to_json_binary(&c.extension()?)?));
+        }
         Ok(None)
     }
 
@@ -142,6 +116,10 @@ impl Credential {
             name: self.name(),
             hrp: self.hrp(),
             extension: self.extension().unwrap_or(None),
+            label: None,
+            created_at: None,
+            last_used: None,
+            role: None,
         }
     }
 
@@ -190,16 +168,95 @@ impl Credential {
     ) -> Result<(), AuthError> 
         where Self: Sized
     {   
+        self.assert_with(api, storage, env, &[])
+    }
+
+
+    #[cfg(all(feature = "wasm", feature = "storage"))]
+    pub(crate) fn assert_with(
+        &self, 
+        api       :  &dyn Api, 
+        storage   :  &dyn Storage,
+        env       :  &Env, 
+        verifiers :  Verifiers,
+    ) -> Result<(), AuthError> {
+        ensure_unlocked(storage)?;
         ensure!(has_credential(storage, &self.id()), AuthError::NotFound);
-        self.verify_cosmwasm(api)?;
+        self.assert_extension(storage)?;
+        self.verify_with(api, verifiers)?;
         #[cfg(feature = "replay")]
-        {
-            let msg : MsgDataToVerify = from_json(&self.message())?;
-            msg.validate_cosmwasm(storage, env)?;
+        self.replay_data(env)?.validate_cosmwasm(storage, env)?;
+        Ok(())
+    }
+
+
+    /// Sign-In with Ethereum messages don't list the actions and can only authorise queries
+    #[cfg(all(feature = "wasm", feature = "storage"))]
+    pub(crate) fn authorises_actions(&self) -> bool {
+        #[cfg(feature = "siwe")]
+        if let Credential::Siwe(_) = self {
+            return false;
+        }
+        true
+    }
+
+
+    /// Verify the credential passing the custom ones to the verifiers of the contract
+    #[cfg(all(feature = "wasm", feature = "storage"))]
+    #[cfg_attr(not(feature = "custom"), allow(unused_variables))]
+    pub(crate) fn verify_with(&self, api: &dyn Api, verifiers: Verifiers) -> Result<(), AuthError> {
+        #[cfg(feature = "custom")]
+        if let Credential::Custom(c) = self {
+            return c.verify_cosmwasm_with(api, verifiers);
+        }
+        self.verify_cosmwasm(api)
+    }
+
+
+    /// Check the parts of the credential that must match the extension stored with it
+    #[cfg(all(feature = "wasm", feature = "storage"))]
+    #[cfg_attr(not(any(feature = "siwe", feature = "passkeys", feature = "custom")), allow(unused_variables))]
+    pub(crate) fn assert_extension(&self, storage: &dyn Storage) -> Result<(), AuthError> {
+        #[cfg(feature = "custom")]
+        if let Credential::Custom(c) = self {
+            // the verifier must be the one the credential has been registered with
+            let info = get_cred_info(storage, self.id())?;
+            ensure!(
+                info.name == CredentialName::Custom && info.extension == Some(Binary::from(c.name.as_bytes())),
+                AuthError::InvalidData(format!("Credential isn't stored for the '{}' verifier", c.name))
+            );
+            return Ok(());
+        }
+        #[cfg(feature = "siwe")]
+        if let Credential::Siwe(c) = self {
+            let stored = get_cred_info(storage, self.id())?.extension.ok_or_else(||
+                AuthError::MissingData("Extension must be stored for 'siwe'".to_string())
+            )?;
+            return c.assert_extension(&from_json(stored)?);
+        }
+        #[cfg(feature = "passkeys")]
+        if let Credential::Passkey(c) = self {
+            let stored = get_cred_info(storage, self.id())?.extension.ok_or_else(||
+                AuthError::MissingData("Extension must be stored for 'passkey'".to_string())
+            )?;
+            return c.assert_extension(&from_json(stored)?);
         }
         Ok(())
     }
 
+
+    /// Chain, contract and nonce that the message of the credential has been signed for
+    #[cfg(all(feature = "wasm", feature = "replay"))]
+    #[cfg_attr(not(feature = "siwe"), allow(unused_variables))]
+    pub(crate) fn replay_data(&self, env: &Env) -> Result<MsgDataToVerify, AuthError> {
+        #[cfg(feature = "siwe")]
+        if let Credential::Siwe(c) = self {
+            c.validate_time(env)?;
+            return c.replay_data();
+        }
+        from_json(self.message())
+    }
+
     
     #[cfg(all(feature = "wasm", feature = "storage"))]
     pub fn save_cosmwasm(&self, 
@@ -220,57 +277,3 @@ impl Credential {
 
     
 }
-
-impl Verifiable for Credential {
-
-    fn id(&self) -> CredentialId {
-        self.value().id()
-    }
-
-    fn hrp(&self) -> Option<String> {
-        self.value().hrp()
-    }
-
-    fn validate(&self) -> Result<(), AuthError> {
-        self.value().validate()
-    }
-
-    #[cfg(feature = "native")]
-    fn verify(&self) -> Result<(), AuthError> {
-        self.value().verify()
-    }
-
-    #[cfg(feature = "wasm")]
-    fn verify_cosmwasm(&self,  api:  &dyn Api) -> Result<(), AuthError>  
-        where Self: Sized
-    {
-        self.validate()?;
-        match self {
-            Credential::Caller(c) => c.verify_cosmwasm(api),
-            #[cfg(feature = "passkeys")]
-            Credential::Passkey(c) => c.verify_cosmwasm(api),
-            #[cfg(feature = "ethereum")]
-            Credential::EthPersonalSign(c) => c.verify_cosmwasm(api),
-            #[cfg(feature = "cosmos")]
-            Credential::CosmosArbitrary(c) => c.verify_cosmwasm(api),
-            #[cfg(all(not(feature = "curves"), feature = "ed25519"))]
-            Credential::Ed25519(c) => c.verify_cosmwasm(api),
-            #[cfg(feature = "curves")]
-            curve => {
-                match curve {
-                    Credential::Secp256k1(c) => c.verify_cosmwasm(api),
-                    Credential::Secp256r1(c) => c.verify_cosmwasm(api),
-                    Credential::Ed25519(c) => c.verify_cosmwasm(api),
-                    _ => unreachable!(),
-                }
-            },
-        }
-    }
-
-}
-
-
-
-
-
-
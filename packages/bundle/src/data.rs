@@ -1,8 +1,8 @@
 #![allow(unreachable_code)]
 
 use saa_common::{
-    format, ensure,
-    CredentialId, CredentialName, 
+    format, ensure, Binary,
+    CredentialId, CredentialName, CredentialRole,
     Vec, Verifiable, AuthError
 };
 use saa_auth::caller::Caller;
@@ -11,22 +11,45 @@ use saa_schema::wasm_serde;
 #[cfg(feature = "wasm")]
 use saa_common::cosmwasm::{Api, Env, MessageInfo, Storage};
 
+#[cfg(all(feature = "wasm", feature = "storage"))]
+use {saa_common::{cosmwasm::Event, vec}, crate::{events, Verifiers}};
+
 #[cfg(feature = "substrate")]
 use saa_common::substrate::{InkEnvironment, InkApi};
 
 #[cfg(all(feature = "wasm", feature = "storage"))]
-use saa_common::{storage::*, messages::*};
+use saa_common::{storage::*, CredentialInfo};
 
 
 use crate::{Credential, CredentialsWrapper};
 
 
+/// Maximum length of a human readable label of a credential
+pub const MAX_LABEL_LENGTH: usize = 64;
+
+
 
 #[wasm_serde]
 pub struct CredentialData {
     pub credentials     :  Vec<Credential>,
     pub with_caller     :  Option<bool>,
     pub primary_index   :  Option<u8>,
+    /// optional settings of the credentials under the same indices
+    pub settings        :  Option<Vec<CredentialSettings>>,
+}
+
+
+#[wasm_serde]
+#[derive(Default)]
+pub struct CredentialSettings {
+    /// human readable label to store with the credential e.g. "YubiKey"
+    pub label: Option<String>,
+    /// permissions of the credential. Admin by default
+    pub role: Option<CredentialRole>,
+    /// maximum amounts of native tokens the credential can spend. Unrestricted by default.
+    /// Tokens of other contracts e.g. CW20 ones are not limited
+    #[cfg(all(feature = "cosmwasm", feature = "spending"))]
+    pub spending_limits: Option<Vec<saa_common::spending::SpendingLimit>>,
 }
 
 
@@ -34,6 +57,14 @@ pub struct CredentialData {
 pub enum UpdateOperation<A = CredentialData> {
     Add(A),
     Remove(A),
+    /// Add new credentials and remove the old ones by their ids in a single step.
+    /// Removing the verifying credential makes the primary or the first added admin credential a new one
+    Replace {
+        add: A,
+        remove: Vec<Binary>,
+    },
+    /// Make a stored admin credential with the given id the verifying one
+    SetPrimary(Binary),
 }
 
 
@@ -55,6 +86,7 @@ impl CredentialData {
             credentials,
             with_caller: Some(true),
             primary_index: self.primary_index,
+            settings: self.settings.clone(),
         }
     }
 
@@ -71,6 +103,48 @@ impl CredentialData {
     }
 
 
+    /// Info of a credential under the given index to be stored after the user settings
+    #[cfg(all(feature = "wasm", feature = "storage"))]
+    fn stored_info(&self, index: usize, env: &Env) -> CredentialInfo {
+        let mut info = self.credentials[index].info();
+        if let Some(settings) = self.settings.as_ref().and_then(|s| s.get(index)) {
+            info.label = settings.label.clone();
+            info.role = settings.role.clone();
+        }
+        info.created_at = Some(env.into());
+        info
+    }
+
+
+    /// Save the spending limits of a credential under the given index if there are any
+    #[cfg(all(feature = "cosmwasm", feature = "spending"))]
+    fn save_spending_limits(&self, storage: &mut dyn Storage, index: usize) -> Result<(), AuthError> {
+        let limits = self.settings.as_ref()
+            .and_then(|s| s.get(index))
+            .and_then(|s| s.spending_limits.clone());
+        if let Some(limits) = limits {
+            saa_common::spending::set_spending_limits(storage, &self.credentials[index].id(), limits)?;
+        }
+        Ok(())
+    }
+
+
+    /// Role of a credential under the given index as requested in the settings
+    pub fn role_at(&self, index: usize) -> CredentialRole {
+        self.settings.as_ref()
+            .and_then(|s| s.get(index))
+            .and_then(|s| s.role.clone())
+            .unwrap_or(CredentialRole::Admin)
+    }
+
+
+    /// Index of the primary credential that is either set explicitly or the first one
+    #[cfg(all(feature = "wasm", feature = "storage"))]
+    fn primary_position(&self) -> usize {
+        self.primary_index.unwrap_or(0) as usize
+    }
+
+
     #[cfg(all(feature = "wasm", feature = "replay"))]
     pub fn assert_signed(
         &self, 
@@ -78,15 +152,16 @@ impl CredentialData {
         env: &Env,
     ) -> Result<(), AuthError> {
         let first = self.credentials.first().unwrap();
-        let first_data : MsgDataToVerify = saa_common::from_json(&first.message())?;
+        let first_data = first.replay_data(env)?;
         first_data.validate_cosmwasm(storage, env)?;
         let nonce = first_data.nonce.clone();
         
         self.credentials().iter().skip(1).map(|c| {
-            let data : MsgDataToVerify = saa_common::from_json(&c.message())?;
+            let data = c.replay_data(env)?;
             ensure!(data.chain_id == first_data.chain_id, AuthError::ChainIdMismatch);
             ensure!(data.contract_address == first_data.contract_address, AuthError::ContractMismatch);
             ensure!(data.nonce == nonce, AuthError::DifferentNonce);
+            ensure!(data.account_id == first_data.account_id, AuthError::AccountMismatch);
             Ok(())
         }).collect::<Result<(), AuthError> >()?;
 
@@ -100,8 +175,10 @@ impl CredentialData {
         api: &dyn Api,
         storage: &dyn Storage, 
         env: &Env,
-        info :  &MessageInfo
+        info :  &MessageInfo,
+        verifiers: Verifiers,
     ) -> Result<(), AuthError> {
+        ensure_unlocked(storage)?;
         if self.with_caller.unwrap_or(false) {
             self.with_caller_cosmwasm(info).validate()?;
             let caller = CALLER.load(storage).unwrap_or(None);
@@ -114,12 +191,21 @@ impl CredentialData {
 
         ensure!(
             self.credentials.iter().all(|c| {
-                has_credential(storage, &c.id()) && c.verify_cosmwasm(api).is_ok()
+                has_credential(storage, &c.id()) && c.verify_with(api, verifiers).is_ok()
             }
             ), 
             AuthError::NotFound
         );
 
+        self.credentials.iter().try_for_each(|c| c.assert_extension(storage))?;
+
+        ensure!(
+            self.credentials.iter().all(|c| {
+                c.authorises_actions() && get_cred_info(storage, c.id()).is_ok_and(|i| i.role().can_update())
+            }),
+            AuthError::Unauthorized
+        );
+
         #[cfg(feature = "replay")]
         self.assert_signed(storage, env)?;
 
@@ -129,6 +215,7 @@ impl CredentialData {
 
 
     #[cfg(all(feature = "wasm", feature = "storage"))]
+    #[deprecated(note = "use `update` that returns the events")]
     pub fn update_cosmwasm(
         &self,
         op: UpdateOperation,
@@ -137,51 +224,186 @@ impl CredentialData {
         env: &Env, 
         info: &MessageInfo
     ) -> Result<(), AuthError> {
+        self.update(op, api, storage, env, info, None).map(|_| ())
+    }
 
-        let new = match &op {
-            UpdateOperation::Add(data) => data,
-            UpdateOperation::Remove(data) => data,
-        };
-        
-        self.assert_cosmwasm(api, storage, env, info)?;
-        new.assert_signed(storage, env)?;
+
+    /// Apply the operation to the stored credentials if the credentials of `self` are the admin ones
+    /// and return the events to attach to the response. Custom credentials are checked with the given verifiers
+    #[cfg(all(feature = "wasm", feature = "storage"))]
+    pub fn update(
+        &self,
+        op: UpdateOperation,
+        api: &dyn Api, 
+        storage: &mut dyn Storage,
+        env: &Env, 
+        info: &MessageInfo,
+        verifiers: Option<Verifiers>,
+    ) -> Result<Vec<Event>, AuthError> {
+        let verifiers = verifiers.unwrap_or_default();
+        self.assert_cosmwasm(api, storage, env, info, verifiers)?;
+        let mut events = Vec::new();
 
         #[cfg(feature = "replay")]
-        increment_account_number(storage)?;
+        {
+            match &op {
+                UpdateOperation::Add(data) => data.assert_signed(storage, env)?,
+                UpdateOperation::Remove(data) => data.assert_signed(storage, env)?,
+                UpdateOperation::Replace { add, .. } => add.assert_signed(storage, env)?,
+                UpdateOperation::SetPrimary(_) => {},
+            }
+        }
 
         match op {
             UpdateOperation::Add(data) => {
-                for cred in data.credentials() {
-                    ensure!(!has_credential(storage, &cred.id()), AuthError::AlreadyExists);
-                    cred.save_cosmwasm(api, storage, env, info)?;
-                    if data.primary_index.is_some() {
-                        let primary = data.primary();
-                        if let Credential::Caller(_) = primary {} else {
-                            VERIFYING_CRED_ID.save(storage, &cred.id())?;
-                        }
-                    }
-                }
-                if data.with_caller.unwrap_or(false) {
-                    CALLER.save(storage, &Some(info.sender.to_string()))?;
-                }
+                events.extend(data.add_credentials(api, storage, env, info, verifiers)?);
             },
             UpdateOperation::Remove(data) => {
                 for cred in data.credentials() {
                     let id = cred.id();
                     ensure!(VERIFYING_CRED_ID.load(storage)? != id, AuthError::NoVerifying);
-                    remove_credential(storage, &id)?;
+                    Self::remove_stored(storage, &id)?;
+                    events.push(events::credential_removed(&id));
                 }
                 if data.with_caller.unwrap_or(false) {
                     CALLER.save(storage, &None)?;
                 }
+            },
+            UpdateOperation::Replace { add, remove } => {
+                events.extend(add.add_credentials(api, storage, env, info, verifiers)?);
+                for id in remove.iter() {
+                    ensure!(has_credential(storage, &id.to_vec()), AuthError::NotFound);
+                    Self::remove_stored(storage, &id.to_vec())?;
+                    events.push(events::credential_removed(&id.to_vec()));
+                }
+                let verifying = VERIFYING_CRED_ID.load(storage)?;
+                if remove.contains(&Binary::new(verifying)) {
+                    // promote the first added credential that is allowed to manage the account
+                    let index = add.credentials.iter()
+                        .enumerate()
+                        .position(|(i, c)| c.name() != CredentialName::Caller && add.role_at(i).can_update())
+                        .ok_or(AuthError::NoVerifying)?;
+                    let id = add.credentials[index].id();
+                    ensure!(!remove.contains(&Binary::new(id.clone())), AuthError::NoVerifying);
+                    VERIFYING_CRED_ID.save(storage, &id)?;
+                    events.push(events::primary_changed(&id));
+                }
+            },
+            UpdateOperation::SetPrimary(id) => {
+                let info = get_cred_info(storage, id.to_vec())?;
+                ensure!(info.role().can_update(), AuthError::Unauthorized);
+                VERIFYING_CRED_ID.save(storage, &id.to_vec())?;
+                events.push(events::primary_changed(&id.to_vec()));
+            }
+        }
+        #[cfg(feature = "replay")]
+        events.push(events::consume_nonce(storage)?);
+        Ok(events)
+    }
+
+
+    /// Verify and store the credentials optionally making the primary one the verifying credential
+    #[cfg(all(feature = "wasm", feature = "storage"))]
+    fn add_credentials(
+        &self,
+        api: &dyn Api, 
+        storage: &mut dyn Storage,
+        env: &Env, 
+        info: &MessageInfo,
+        verifiers: Verifiers,
+    ) -> Result<Vec<Event>, AuthError> {
+        let mut events = Vec::new();
+        for (index, cred) in self.credentials().iter().enumerate() {
+            if let Credential::Caller(_) = cred {
+                continue;
             }
+            ensure!(!has_credential(storage, &cred.id()), AuthError::AlreadyExists);
+            cred.verify_with(api, verifiers)?;
+            save_credential(storage, &cred.id(), &self.stored_info(index, env))?;
+            #[cfg(all(feature = "cosmwasm", feature = "spending"))]
+            self.save_spending_limits(storage, index)?;
+            events.push(events::credential_added(&cred.id(), cred.name()));
         }
+        if self.primary_index.is_some() {
+            if let Credential::Caller(_) = self.primary() {} else {
+                ensure!(self.role_at(self.primary_position()).can_update(), AuthError::Unauthorized);
+                VERIFYING_CRED_ID.save(storage, &self.primary_id())?;
+                events.push(events::primary_changed(&self.primary_id()));
+            }
+        }
+        if self.with_caller.unwrap_or(false) {
+            CALLER.save(storage, &Some(info.sender.to_string()))?;
+            events.push(events::credential_added(&info.sender.as_bytes().to_vec(), CredentialName::Caller));
+        }
+        Ok(events)
+    }
+
+
+    /// Remove a stored credential together with all the data associated with it
+    #[cfg(all(feature = "wasm", feature = "storage"))]
+    fn remove_stored(storage: &mut dyn Storage, id: &CredentialId) -> Result<(), AuthError> {
+        remove_credential(storage, id)?;
+        #[cfg(all(feature = "cosmwasm", feature = "spending"))]
+        saa_common::spending::remove_spending_limits(storage, id)?;
         Ok(())
     }
 
 
 
+    /// Lift the lock from the account. Requires signatures of as many admin credentials as
+    /// set in the lock policy or a single one after the delay since the account was locked
     #[cfg(all(feature = "wasm", feature = "storage"))]
+    pub fn unlock(
+        &self,
+        api: &dyn Api,
+        storage: &mut dyn Storage,
+        env: &Env,
+        verifiers: Option<Verifiers>,
+    ) -> Result<Vec<Event>, AuthError> {
+        let verifiers = verifiers.unwrap_or_default();
+        let lock = get_account_lock(storage)?
+            .ok_or(AuthError::NotLocked)?;
+        self.validate()?;
+
+        let mut admins : Vec<CredentialId> = Vec::new();
+        for cred in self.credentials() {
+            if let Credential::Caller(_) = cred {
+                continue;
+            }
+            let id = cred.id();
+            ensure!(
+                cred.authorises_actions() && get_cred_info(storage, id.clone())?.role().can_update(),
+                AuthError::Unauthorized
+            );
+            cred.assert_extension(storage)?;
+            cred.verify_with(api, verifiers)?;
+            if !admins.contains(&id) {
+                admins.push(id);
+            }
+        }
+        ensure!(!admins.is_empty(), AuthError::NoCredentials);
+
+        #[cfg(feature = "replay")]
+        self.assert_signed(storage, env)?;
+
+        let policy = get_lock_policy(storage)?;
+        let delay_passed = env.block.time.seconds() >= lock.locked_at.time.saturating_add(policy.delay);
+        ensure!(
+            delay_passed || admins.len() >= policy.threshold as usize,
+            AuthError::Unauthorized
+        );
+
+        ACCOUNT_LOCK.remove(storage);
+        let mut events = vec![events::account_unlocked()];
+        #[cfg(feature = "replay")]
+        events.push(events::consume_nonce(storage)?);
+        Ok(events)
+    }
+
+
+
+    #[cfg(all(feature = "wasm", feature = "storage"))]
+    #[deprecated(note = "use `save` that returns the events")]
     pub fn save_cosmwasm(
         &self, 
         api: &dyn Api, 
@@ -189,8 +411,26 @@ impl CredentialData {
         env: &Env, 
         info: &MessageInfo
     ) -> Result<(), AuthError> {
+        self.save(api, storage, env, info, None).map(|_| ())
+    }
+
+
+    /// Verify and store the credentials of a new account and return the events to attach
+    /// to the response. Custom credentials are checked with the given verifiers
+    #[cfg(all(feature = "wasm", feature = "storage"))]
+    pub fn save(
+        &self, 
+        api: &dyn Api, 
+        storage: &mut dyn Storage,
+        env: &Env, 
+        info: &MessageInfo,
+        verifiers: Option<Verifiers>,
+    ) -> Result<Vec<Event>, AuthError> {
+        let verifiers = verifiers.unwrap_or_default();
+        let mut events = Vec::new();
         let data = if self.with_caller.unwrap_or(false) {
             CALLER.save(storage, &Some(info.sender.to_string()))?;
+            events.push(events::credential_added(&info.sender.as_bytes().to_vec(), CredentialName::Caller));
             self.with_caller_cosmwasm(info)
         } else {
             self.clone()
@@ -199,7 +439,7 @@ impl CredentialData {
         #[cfg(feature = "replay")]
         {
             self.assert_signed(storage, env)?;
-            increment_account_number(storage)?;
+            events.push(events::consume_nonce(storage)?);
         }  
 
         let mut verifying_found = false;
@@ -208,28 +448,35 @@ impl CredentialData {
             if let Credential::Caller(_) = data.primary() {
                 // skio the caller since it is can't be used to verify messages
             } else {
+                // verifying credential can't be removed and must always be able to manage the account
+                ensure!(data.role_at(data.primary_position()).can_update(), AuthError::Unauthorized);
                 VERIFYING_CRED_ID.save(storage, &data.primary_id())?;
                 verifying_found = true;
             }
         }
 
-        for cred in self.credentials() {
+        for (index, cred) in self.credentials().iter().enumerate() {
 
             if let Credential::Caller(_) = cred {
                 continue;
             }
 
-            cred.verify_cosmwasm(api)?;
+            cred.verify_with(api, verifiers)?;
 
-            if !verifying_found {
+            if !verifying_found && self.role_at(index).can_update() {
                 VERIFYING_CRED_ID.save(storage, &cred.id())?;
                 verifying_found = true;
             }
-            save_credential(storage, &cred.id(), &cred.info())?;
+            save_credential(storage, &cred.id(), &self.stored_info(index, env))?;
+            #[cfg(all(feature = "cosmwasm", feature = "spending"))]
+            self.save_spending_limits(storage, index)?;
+            events.push(events::credential_added(&cred.id(), cred.name()));
         }
 
         ensure!(verifying_found, AuthError::NoVerifying);
-        Ok(())
+        set_storage_version(storage)?;
+        events.push(events::primary_changed(&VERIFYING_CRED_ID.load(storage)?));
+        Ok(events)
         
     }
 
@@ -265,18 +512,37 @@ impl Verifiable for CredentialData {
                     .iter()
                     .filter(|c| c.name() == CredentialName::Caller)
                     .count() == 1,
-                AuthError::generic("No caller credential found")
+                AuthError::MissingData("No caller credential found".to_string())
             );
         } else if creds.len() == 0 {
             return Err(AuthError::NoCredentials);
         } else if creds.len() > 255 {
-            return Err(AuthError::Generic(format!("Too many credentials: {}", creds.len())));
+            return Err(AuthError::InvalidData(format!("Too many credentials: {}", creds.len())));
+        }
+
+        if let Some(settings) = self.settings.as_ref() {
+            ensure!(
+                settings.len() <= creds.len(),
+                AuthError::InvalidData("More settings than credentials".to_string())
+            );
+            ensure!(
+                !settings.iter().any(|s| s.label.as_ref().is_some_and(|l| l.chars().count() > MAX_LABEL_LENGTH)),
+                AuthError::InvalidData(format!("Labels can't be longer than {} characters", MAX_LABEL_LENGTH))
+            );
+            // an admin could lift its own limits by updating the credentials
+            #[cfg(all(feature = "cosmwasm", feature = "spending"))]
+            ensure!(
+                settings.iter().enumerate().all(|(i, s)| 
+                    s.spending_limits.as_ref().is_none_or(|l| l.is_empty()) || !self.role_at(i).can_update()
+                ),
+                AuthError::InvalidData("Admin credentials can't have spending limits".to_string())
+            );
         }
 
         if let Some(index) = self.primary_index() {
             let len = creds.len() + if with_caller { 1 } else { 0 };
             if *index as usize >= len {
-                return Err(AuthError::Generic(format!("Primary index {} is out of bounds", index)));
+                return Err(AuthError::InvalidData(format!("Primary index {} is out of bounds", index)));
             }
         }
         creds.iter().map(|c| c.validate()).collect()
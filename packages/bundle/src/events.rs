@@ -0,0 +1,69 @@
+use saa_common::{cosmwasm::Event, Binary, CredentialId, CredentialName};
+
+#[cfg(feature = "replay")]
+use saa_common::{cosmwasm::Storage, storage::{increment_account_number, ACCOUNT_NUMBER}, AuthError};
+
+
+pub const CREDENTIAL_ADDED   : &str = "saa_credential_added";
+pub const CREDENTIAL_REMOVED : &str = "saa_credential_removed";
+pub const CREDENTIAL_USED    : &str = "saa_credential_used";
+pub const PRIMARY_CHANGED    : &str = "saa_primary_changed";
+pub const NONCE_CONSUMED     : &str = "saa_nonce_consumed";
+pub const CREDENTIALS_RESET  : &str = "saa_credentials_reset";
+pub const ACCOUNT_LOCKED     : &str = "saa_account_locked";
+pub const ACCOUNT_UNLOCKED   : &str = "saa_account_unlocked";
+
+
+fn id_attr(id: &CredentialId) -> String {
+    Binary::new(id.clone()).to_base64()
+}
+
+
+pub(crate) fn credential_added(id: &CredentialId, name: CredentialName) -> Event {
+    Event::new(CREDENTIAL_ADDED)
+        .add_attribute("id", id_attr(id))
+        .add_attribute("name", name.as_str())
+}
+
+
+pub(crate) fn credential_removed(id: &CredentialId) -> Event {
+    Event::new(CREDENTIAL_REMOVED).add_attribute("id", id_attr(id))
+}
+
+
+#[cfg(feature = "replay")]
+pub(crate) fn credential_used(id: &CredentialId, name: CredentialName) -> Event {
+    Event::new(CREDENTIAL_USED)
+        .add_attribute("id", id_attr(id))
+        .add_attribute("name", name.as_str())
+}
+
+
+pub(crate) fn primary_changed(id: &CredentialId) -> Event {
+    Event::new(PRIMARY_CHANGED).add_attribute("id", id_attr(id))
+}
+
+
+pub(crate) fn credentials_reset() -> Event {
+    Event::new(CREDENTIALS_RESET)
+}
+
+
+#[cfg(feature = "replay")]
+pub(crate) fn account_locked(id: &CredentialId) -> Event {
+    Event::new(ACCOUNT_LOCKED).add_attribute("id", id_attr(id))
+}
+
+
+pub(crate) fn account_unlocked() -> Event {
+    Event::new(ACCOUNT_UNLOCKED)
+}
+
+
+/// Increment the account number and report the nonce that has been used
+#[cfg(feature = "replay")]
+pub(crate) fn consume_nonce(storage: &mut dyn Storage) -> Result<Event, AuthError> {
+    let nonce = ACCOUNT_NUMBER.may_load(storage)?.unwrap_or_default();
+    increment_account_number(storage)?;
+    Ok(Event::new(NONCE_CONSUMED).add_attribute("nonce", nonce.to_string()))
+}
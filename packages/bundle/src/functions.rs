@@ -1,12 +1,13 @@
 #[cfg(all(feature = "wasm", feature = "storage"))]
 use saa_common::{
-    CredentialId, CredentialName, AuthError, Binary, ensure, 
-    cosmwasm::{Api, Env, Storage},
+    AccountLock, CredentialName, CredentialInfo, AuthError, Binary, Verifiable, ensure, 
+    cosmwasm::{Api, Env, Event, Storage},
+    vec,
     storage::*,
     messages::*
 };
 #[cfg(all(feature = "wasm", feature = "storage"))]
-use crate::Credential;
+use crate::{Credential, Verifiers, construct::CredentialParts};
 
 
 
@@ -30,15 +31,53 @@ pub fn get_all_credentials(
 
 
 
+#[cfg(all(feature = "wasm", feature = "storage", feature = "iterator"))]
+pub fn get_credentials_page(
+    storage:      &dyn Storage,
+    start_after:  Option<Binary>,
+    limit:        Option<u32>,
+    name:         Option<CredentialName>,
+) -> Result<AccountCredentials, AuthError> {
+
+    let credentials = get_credentials_paginated(
+        storage, 
+        start_after.map(|id| id.to_vec()), 
+        limit, 
+        name
+    )?;
+
+    let verifying_id = VERIFYING_CRED_ID.load(storage)?;
+    let caller = CALLER.load(storage).unwrap_or(None);
+
+    Ok(AccountCredentials {
+        credentials,
+        native_caller: caller.is_some(),
+        verifying_id: Binary::new(verifying_id),
+    })
+}
+
+
 #[cfg(all(feature = "wasm", feature = "storage"))]
+#[deprecated(note = "use `clear_credentials` that returns the events")]
 pub fn reset_credentials(
     storage: &mut dyn Storage,
 ) -> Result<(), AuthError> {
+    clear_credentials(storage).map(|_| ())
+}
+
+
+/// Remove all the credentials, the caller and the lock of the account
+#[cfg(all(feature = "wasm", feature = "storage"))]
+pub fn clear_credentials(
+    storage: &mut dyn Storage,
+) -> Result<Vec<Event>, AuthError> {
     VERIFYING_CRED_ID.remove(storage);
     CALLER.remove(storage);
+    ACCOUNT_LOCK.remove(storage);
+    LOCK_POLICY.remove(storage);
     #[cfg(all(feature = "secretwasm", not(feature = "cosmwasm")))]
     {
-        let keys : Vec<CredentialId> = CREDENTIAL_INFOS
+        let keys : Vec<saa_common::CredentialId> = CREDENTIAL_INFOS
             .iter_keys(storage)?.map(|k| k.unwrap()).collect();
 
         for key in keys {
@@ -47,41 +86,171 @@ pub fn reset_credentials(
     }
     #[cfg(feature = "cosmwasm")]
     CREDENTIAL_INFOS.clear(storage);
-    Ok(())
+    #[cfg(all(feature = "cosmwasm", feature = "spending"))]
+    {
+        SPENDING_LIMITS.clear(storage);
+        SPENDING_RECORDS.clear(storage);
+    }
+    Ok(vec![crate::events::credentials_reset()])
 }
 
 
 #[cfg(all(feature = "wasm", feature = "storage"))]
+#[deprecated(note = "use `verify_queries` that accepts the verifiers of the custom credentials")]
 pub fn verify_signed_queries(
     api: &dyn Api,
     storage: &dyn Storage,
     env: &Env,
     data: SignedDataMsg
 ) -> Result<(), AuthError> {
-    let credential = load_credential(storage, data)?;
-    credential.assert_cosmwasm(api, storage, env)?;
+    verify_queries(api, storage, env, data, None)
+}
+
+
+/// Verify the signed data authorising queries without consuming the nonce.
+/// Custom credentials are checked with the given verifiers
+#[cfg(all(feature = "wasm", feature = "storage"))]
+pub fn verify_queries(
+    api: &dyn Api,
+    storage: &dyn Storage,
+    env: &Env,
+    data: SignedDataMsg,
+    verifiers: Option<Verifiers>,
+) -> Result<(), AuthError> {
+    let (credential, info) = load_credential(storage, data)?;
+    ensure!(info.role().can_query(), AuthError::Unauthorized);
+    credential.assert_with(api, storage, env, verifiers.unwrap_or_default())?;
     Ok(())
 }
 
+
 #[cfg(all(feature = "wasm", feature = "replay"))]
+#[deprecated(note = "use `verify_actions` that returns the events")]
 pub fn verify_signed_actions(
     api: &dyn Api,
     storage: &mut dyn Storage,
     env: &Env,
     data: SignedDataMsg
 ) -> Result<(), AuthError> {
-    let credential = load_credential(storage, data)?;
-    credential.assert_cosmwasm(api, storage, env)?;
-    increment_account_number(storage)?;
+    verify_actions(api, storage, env, data, None).map(|_| ())
+}
+
+
+/// Verify the signed data authorising actions and consume the nonce.
+/// Returns the events to attach to the response
+#[cfg(all(feature = "wasm", feature = "replay"))]
+pub fn verify_actions(
+    api: &dyn Api,
+    storage: &mut dyn Storage,
+    env: &Env,
+    data: SignedDataMsg,
+    verifiers: Option<Verifiers>,
+) -> Result<Vec<Event>, AuthError> {
+    #[cfg(feature = "audit")]
+    let signed = data.data.clone();
+    let credential = load_executor(api, storage, env, data, verifiers)?;
+    // the messages of limited credentials must be inspected with `verify_cosmos_msgs`
+    #[cfg(all(feature = "cosmwasm", feature = "spending"))]
+    ensure!(
+        saa_common::spending::get_spending_limits(storage, &credential.id())?.is_none(),
+        AuthError::Unauthorized
+    );
+    update_last_used(storage, &credential.id(), env)?;
+    #[cfg(feature = "audit")]
+    saa_common::audit::record_action(storage, env, &credential.id(), &signed)?;
+    Ok(vec![
+        crate::events::credential_used(&credential.id(), credential.name()),
+        crate::events::consume_nonce(storage)?,
+    ])
+}
+
+
+/// Same as `verify_actions` but also checks that the signed data authorises exactly the given
+/// messages and records the funds that they are going to spend against the limits of the credential
+#[cfg(all(feature = "cosmwasm", feature = "replay", feature = "spending"))]
+pub fn verify_cosmos_msgs<T>(
+    api: &dyn Api,
+    storage: &mut dyn Storage,
+    env: &Env,
+    data: SignedDataMsg,
+    msgs: &[saa_common::cosmwasm::CosmosMsg<T>],
+    verifiers: Option<Verifiers>,
+) -> Result<Vec<Event>, AuthError> 
+    where T: serde::de::DeserializeOwned + PartialEq
+{
+    assert_messages(&data.data, msgs)?;
+    #[cfg(feature = "audit")]
+    let signed = data.data.clone();
+    let credential = load_executor(api, storage, env, data, verifiers)?;
+    saa_common::spending::assert_spending(storage, env, &credential.id(), msgs)?;
+    update_last_used(storage, &credential.id(), env)?;
+    #[cfg(feature = "audit")]
+    saa_common::audit::record_action(storage, env, &credential.id(), &signed)?;
+    Ok(vec![
+        crate::events::credential_used(&credential.id(), credential.name()),
+        crate::events::consume_nonce(storage)?,
+    ])
+}
+
+
+/// Check that the signed data authorises exactly the given messages
+#[cfg(all(feature = "cosmwasm", feature = "replay"))]
+pub(crate) fn assert_messages<T>(
+    signed: &Binary,
+    msgs: &[saa_common::cosmwasm::CosmosMsg<T>],
+) -> Result<(), AuthError> 
+    where T: serde::de::DeserializeOwned + PartialEq
+{
+    let data : MsgDataToSign<saa_common::cosmwasm::CosmosMsg<T>> = saa_common::from_json(signed)?;
+    ensure!(data.messages == msgs, AuthError::MessagesMismatch);
     Ok(())
 }
 
 
+/// Lock the account and disable all the signed actions until it gets unlocked
+/// with `CredentialData::unlock`. Can be done by a credential of any role
+#[cfg(all(feature = "wasm", feature = "replay"))]
+pub fn lock_account(
+    api: &dyn Api,
+    storage: &mut dyn Storage,
+    env: &Env,
+    data: SignedDataMsg,
+    verifiers: Option<Verifiers>,
+) -> Result<Vec<Event>, AuthError> {
+    let (credential, _) = load_credential(storage, data)?;
+    ensure!(credential.authorises_actions(), AuthError::Unauthorized);
+    credential.assert_with(api, storage, env, verifiers.unwrap_or_default())?;
+    ACCOUNT_LOCK.save(storage, &AccountLock {
+        locked_by: Binary::new(credential.id()),
+        locked_at: env.into(),
+    })?;
+    Ok(vec![
+        crate::events::account_locked(&credential.id()),
+        crate::events::consume_nonce(storage)?,
+    ])
+}
+
+
+#[cfg(all(feature = "wasm", feature = "replay"))]
+fn load_executor(
+    api: &dyn Api,
+    storage: &dyn Storage,
+    env: &Env,
+    data: SignedDataMsg,
+    verifiers: Option<Verifiers>,
+) -> Result<Credential, AuthError> {
+    let (credential, info) = load_credential(storage, data)?;
+    ensure!(info.role().can_execute() && credential.authorises_actions(), AuthError::Unauthorized);
+    credential.assert_with(api, storage, env, verifiers.unwrap_or_default())?;
+    Ok(credential)
+}
+
+
 #[cfg(all(feature = "wasm", feature = "storage"))]
 fn load_credential(
     storage:   &dyn Storage,
     data_msg:  SignedDataMsg
-) -> Result<Credential, AuthError> {
+) -> Result<(Credential, CredentialInfo), AuthError> {
     let initial_id = VERIFYING_CRED_ID.load(storage)?;
 
     let id = match data_msg.payload.clone() {
@@ -101,125 +270,13 @@ fn load_credential(
     };
     let info = get_cred_info(storage, id.clone())?;
 
-    construct_credential(
+    let credential = Credential::construct(info.name.clone(), CredentialParts {
         id, 
-        info.name,
-        data_msg.data, 
-        data_msg.signature, 
-        data_msg.payload.as_ref().map(|p| p.hrp.clone()).unwrap_or(info.hrp),
-        info.extension,
-        data_msg.payload.map(|p| p.extension).flatten(),
-    )
+        message: data_msg.data, 
+        signature: data_msg.signature, 
+        hrp: data_msg.payload.as_ref().map(|p| p.hrp.clone()).unwrap_or(info.hrp.clone()),
+        stored_extension: info.extension.clone(),
+        passed_extension: data_msg.payload.and_then(|p| p.extension),
+    })?;
+    Ok((credential, info))
 }
-
-
-#[cfg(all(feature = "wasm", feature = "storage"))]
-fn construct_credential(
-    id: CredentialId,
-    name: CredentialName,
-    message: Binary,
-    signature: Binary,
-    hrp: Option<String>,
-    stored_extension: Option<Binary>,
-    passed_extension: Option<Binary>,
-) -> Result<Credential, AuthError> {
-    use saa_common::from_json;
-    use saa_auth::passkey::utils::base64_to_url;
-
-    let credential = match name {
-
-        CredentialName::Caller => Credential::Caller(saa_auth::caller::Caller { id }),
-
-        #[cfg(feature = "ethereum")]
-        CredentialName::EthPersonalSign => Credential::EthPersonalSign(saa_auth::eth::EthPersonalSign {
-                message,
-                signature,
-                signer: String::from_utf8(id)?,
-            }
-        ),
-
-        #[cfg(feature = "cosmos")]
-        CredentialName::CosmosArbitrary => Credential::CosmosArbitrary(saa_auth::cosmos::CosmosArbitrary {
-            pubkey: Binary::new(id),
-            message,
-            signature,
-            hrp,
-        }),
-
-        #[cfg(feature = "passkeys")]
-        CredentialName::Passkey => {
-            use saa_auth::passkey::*;
-            ensure!(
-                passed_extension.is_some(),
-                AuthError::generic("Payload must be provided for 'passkey'")
-            );
-            ensure!(
-                stored_extension.is_some(),
-                AuthError::generic("Extension must be stored for 'passkey'")
-            );
-            let extensiom = passed_extension.unwrap();
-            let payload_ext : PasskeyPayload = from_json(&extensiom)?;
-            let stored_ext : PasskeyExtension = from_json(&stored_extension.unwrap())?;
-            let pubkey = payload_ext.pubkey.or(stored_ext.pubkey);
-            ensure!(
-                pubkey.is_some(),
-                AuthError::generic("No public key provided for 'passkey' credential")
-            );
-            let challenge = base64_to_url(&message.to_base64());
-            let client_data = ClientData::new(
-                "webauthn.get".into(),
-                challenge,
-                stored_ext.origin,
-                stored_ext.cross_origin,
-                payload_ext.other_keys.unwrap_or_default()
-            );
-            Credential::Passkey(PasskeyCredential {
-                id: String::from_utf8(id)?,
-                pubkey,
-                signature,
-                client_data,
-                authenticator_data: payload_ext.authenticator_data,
-                user_handle: stored_ext.user_handle,
-            })
-        },
-        #[cfg(all(not(feature = "curves"), feature = "ed25519"))]
-        CredentialName::Ed25519 => Credential::Ed25519(saa_curves::ed25519::Ed25519 {
-            pubkey: Binary::new(id),
-            signature,
-            message,
-        }),
-        #[cfg(feature = "curves")]
-        curves => {
-            let pubkey = Binary::new(id);
-            match curves {
-                CredentialName::Secp256k1 => Credential::Secp256k1(saa_curves::secp256k1::Secp256k1 {
-                    pubkey,
-                    signature,
-                    message,
-                    hrp,
-                }),
-                CredentialName::Secp256r1 => Credential::Secp256r1(saa_curves::secp256r1::Secp256r1 {
-                    pubkey,
-                    signature,
-                    message,
-                }),
-                CredentialName::Ed25519 => Credential::Ed25519(saa_curves::ed25519::Ed25519 {
-                    pubkey,
-                    signature,
-                    message,
-                }),
-                _ => return Err(AuthError::generic("Unsupported curve")),
-            }
-        }
-        #[cfg(any(
-            not(feature = "curves"),
-            not(feature = "ed25519"),
-            not(feature = "passkeys"), 
-            not(feature = "cosmos"), 
-            not(feature = "ethereum"))
-        )]
-        _ => return Err(AuthError::generic("Credential is not enabled")),
-    };
-
-    Ok(credential)
-}
\ No newline at end of file
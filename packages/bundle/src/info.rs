@@ -0,0 +1,44 @@
+use saa_common::{CredentialName, String, ToString, Vec};
+use saa_schema::wasm_serde;
+
+use crate::Credential;
+
+
+/// Version of the library and the credentials that it has been compiled with.
+/// Lets the clients adapt the signing flows to a deployed contract
+#[wasm_serde]
+pub struct LibraryInfo {
+    /// name of the library crate
+    pub name: String,
+    /// version of the library crate
+    pub version: String,
+    /// enabled credential features e.g. `ethereum` or `passkeys`
+    pub features: Vec<String>,
+    /// whether signed messages must follow the format of `MsgDataToSign` with a nonce
+    pub replay: bool,
+    /// credentials that can be used with the contract
+    pub credentials: Vec<CredentialName>,
+}
+
+
+pub fn library_info() -> LibraryInfo {
+    let features = [
+        ("ethereum", cfg!(feature = "ethereum")),
+        ("cosmos", cfg!(feature = "cosmos")),
+        ("passkeys", cfg!(feature = "passkeys")),
+        ("curves", cfg!(feature = "curves")),
+        ("ed25519", cfg!(feature = "ed25519")),
+        ("custom", cfg!(feature = "custom")),
+        ("siwe", cfg!(feature = "siwe")),
+    ];
+    LibraryInfo {
+        name: env!("CARGO_PKG_NAME").to_string(),
+        version: env!("CARGO_PKG_VERSION").to_string(),
+        features: features.iter()
+            .filter(|(_, enabled)| *enabled)
+            .map(|(name, _)| name.to_string())
+            .collect(),
+        replay: cfg!(feature = "replay"),
+        credentials: Credential::supported_names(),
+    }
+}
@@ -2,27 +2,54 @@
 
 pub use saa_common::{
     Verifiable, AuthError, Binary, to_json_binary, from_json,
-    CredentialId, CredentialName, CredentialInfo,
+    CredentialId, CredentialName, CredentialInfo, CredentialRole, BlockStamp,
+    AccountLock, LockPolicy,
     hashes, utils, messages, 
 };
 
 #[cfg(all(feature = "wasm", feature = "storage"))]
 pub use saa_common::storage;
+#[cfg(all(feature = "cosmwasm", feature = "spending"))]
+pub use saa_common::spending::{self, SpendingLimit};
+#[cfg(all(feature = "wasm", feature = "audit"))]
+pub use saa_common::audit::{self, AuditRecord};
 pub use saa_auth::caller::Caller;
 pub use saa_schema::wasm_serde;
+#[cfg(feature = "cosmwasm")]
+pub use saa_schema::{saa_execute, saa_query};
 
 mod data;
 mod wrapper;
 mod functions;
 mod credential;
+mod info;
 
-pub use data::{CredentialData, UpdateOperation};
+#[cfg(all(feature = "wasm", feature = "storage"))]
+mod construct;
+
+#[cfg(all(feature = "wasm", feature = "storage"))]
+pub mod events;
+
+#[cfg(all(feature = "cosmwasm", feature = "replay"))]
+pub mod contract;
+
+#[cfg(feature = "client")]
+pub mod client;
+
+#[cfg(feature = "typescript")]
+pub mod schema;
+
+pub use data::{CredentialData, CredentialSettings, UpdateOperation, MAX_LABEL_LENGTH};
 pub use wrapper::CredentialsWrapper;
 pub use credential::*;
+pub use info::{library_info, LibraryInfo};
 
 #[cfg(all(feature = "wasm", feature = "storage"))]
 pub use functions::*;
 
+#[cfg(all(test, feature = "cosmwasm", feature = "cosmos", feature = "replay", feature = "iterator"))]
+mod tests;
+
 
 #[cfg(feature = "curves")]
 pub use saa_curves::{ed25519::Ed25519, secp256k1::Secp256k1, secp256r1::Secp256r1};
@@ -39,5 +66,18 @@ pub use saa_auth::eth::{EthPersonalSign, utils as eth_utils};
 #[cfg(feature = "cosmos")]
 pub use saa_auth::cosmos::{CosmosArbitrary, utils as cosmos_utils};
 
+#[cfg(feature = "siwe")]
+pub use saa_auth::siwe::{Siwe, SiweMessage};
+
+#[cfg(feature = "custom")]
+pub use saa_auth::custom::{CustomCredential, CustomVerifier};
+
+/// Verifiers of the custom credentials that the contract passes to the checks
+#[cfg(feature = "custom")]
+pub type Verifiers<'a> = &'a [&'a dyn CustomVerifier];
+/// Without `custom` feature there are no verifiers and only an empty list or `None` can be passed
+#[cfg(all(feature = "wasm", feature = "storage", not(feature = "custom")))]
+pub type Verifiers<'a> = &'a [core::convert::Infallible];
+
 #[cfg(feature = "native")]
 pub use saa_common::crypto;
@@ -0,0 +1,43 @@
+//! JSON schemas of the types shared with the clients and the TypeScript definitions generated from them
+
+use saa_schema::{
+    schemars::{schema::RootSchema, schema_for},
+    ts::{TsGenerator, TypeParam},
+};
+use saa_common::messages::{AccountCredentials, AuthPayload, MsgDataToSign, SignedDataMsg};
+
+use crate::{Credential, CredentialData, LibraryInfo, UpdateOperation};
+
+
+/// Root schemas of the shared types under the names of their files
+pub fn schemas() -> Vec<(&'static str, RootSchema)> {
+    vec![
+        ("credential",          schema_for!(Credential)),
+        ("credential_data",     schema_for!(CredentialData)),
+        ("update_operation",    schema_for!(UpdateOperation)),
+        ("auth_payload",        schema_for!(AuthPayload)),
+        ("msg_data_to_sign",    schema_for!(MsgDataToSign)),
+        ("signed_data_msg",     schema_for!(SignedDataMsg)),
+        ("account_credentials", schema_for!(AccountCredentials)),
+        ("library_info",        schema_for!(LibraryInfo)),
+    ]
+}
+
+
+/// TypeScript definitions of the shared types. `MsgDataToSign` stays generic over the messages
+pub fn typescript() -> String {
+    let mut gen = TsGenerator::new()
+        .alias("UpdateOperation_for_CredentialData", "UpdateOperation")
+        .alias("AuthPayload_for_Binary", "AuthPayload");
+
+    gen.add("Credential", &schema_for!(Credential))
+        .add("CredentialData", &schema_for!(CredentialData))
+        .add("UpdateOperation", &schema_for!(UpdateOperation))
+        .add("AuthPayload", &schema_for!(AuthPayload))
+        .add_generic("MsgDataToSign", ("M", "string"), &schema_for!(MsgDataToSign<TypeParam>))
+        .add("SignedDataMsg", &schema_for!(SignedDataMsg))
+        .add("AccountCredentials", &schema_for!(AccountCredentials))
+        .add("LibraryInfo", &schema_for!(LibraryInfo));
+
+    gen.render()
+}
@@ -0,0 +1,91 @@
+mod audit;
+mod client;
+mod custom;
+mod events;
+mod info;
+mod lock;
+mod metadata;
+mod namespace;
+mod roles;
+mod rotation;
+mod schema;
+mod serialization;
+mod siwe;
+mod spending;
+
+
+use cosmwasm_std::Env;
+use k256::ecdsa::{signature::hazmat::PrehashSigner, Signature, SigningKey};
+use saa_auth::cosmos::{utils::sign_doc_arb_036, CosmosArbitrary};
+use saa_common::{
+    hashes::sha256, to_json_binary, utils::pubkey_to_address, Binary, Verifiable,
+    messages::{AuthPayload, MsgDataToSign, SignedDataMsg}, 
+};
+
+use crate::{Credential, CredentialData};
+
+
+pub fn signing_key(seed: u8) -> SigningKey {
+    SigningKey::from_bytes(&[seed; 32].into()).unwrap()
+}
+
+
+/// Message following the replay protection format for the given nonce
+pub fn data_to_sign(env: &Env, nonce: u128) -> Binary {
+    to_json_binary(&MsgDataToSign {
+        chain_id: env.block.chain_id.clone(),
+        contract_address: env.contract.address.to_string(),
+        messages: vec![String::from("test")],
+        nonce: nonce.to_string(),
+        account_id: None,
+    }).unwrap()
+}
+
+
+pub fn cosmos_credential(key: &SigningKey, message: Binary) -> Credential {
+    let pubkey = key.verifying_key().to_encoded_point(true).as_bytes().to_vec();
+    let address = pubkey_to_address(&pubkey, "cosmos").unwrap();
+    let digest = sha256(sign_doc_arb_036(&address, &message).as_bytes());
+    let signature : Signature = key.sign_prehash(&digest).unwrap();
+
+    Credential::CosmosArbitrary(CosmosArbitrary {
+        pubkey: pubkey.into(),
+        signature: signature.to_bytes().to_vec().into(),
+        message,
+        hrp: Some("cosmos".into()),
+    })
+}
+
+
+pub fn credential_data(credentials: Vec<Credential>) -> CredentialData {
+    CredentialData {
+        credentials,
+        with_caller: None,
+        primary_index: None,
+        settings: None,
+    }
+}
+
+
+/// Signed message for a stored cosmos credential signed with the given key
+pub fn signed_msg(key: &SigningKey, env: &Env, nonce: u128) -> SignedDataMsg {
+    signed_data(key, data_to_sign(env, nonce))
+}
+
+
+/// Given data signed by a stored cosmos credential with the given key
+pub fn signed_data(key: &SigningKey, data: Binary) -> SignedDataMsg {
+    let Credential::CosmosArbitrary(cred) = cosmos_credential(key, data) else {
+        unreachable!()
+    };
+    SignedDataMsg {
+        payload: Some(AuthPayload {
+            credential_id: Some(cred.id()),
+            hrp: cred.hrp.clone(),
+            address: None,
+            extension: None,
+        }),
+        data: cred.message,
+        signature: cred.signature,
+    }
+}
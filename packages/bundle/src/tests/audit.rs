@@ -0,0 +1,50 @@
+use cosmwasm_std::testing::{mock_dependencies, mock_env, mock_info};
+use saa_common::{audit::{audit_count, get_audit_log, AUDIT_CAPACITY}, BlockStamp};
+
+use crate::verify_actions;
+use super::*;
+
+
+#[test]
+fn signed_actions_are_recorded() {
+    let mut deps = mock_dependencies();
+    let mut env = mock_env();
+    let first = cosmos_credential(&signing_key(1), data_to_sign(&env, 0));
+    credential_data(vec![first.clone()]).save(&deps.api, &mut deps.storage, &env, &mock_info("alice", &[]), None).unwrap();
+    assert!(get_audit_log(&deps.storage, None, None).unwrap().is_empty());
+
+    env.block.height += 1;
+    let msg = signed_msg(&signing_key(1), &env, 1);
+    verify_actions(&deps.api, &mut deps.storage, &env, msg.clone(), None).unwrap();
+
+    let log = get_audit_log(&deps.storage, None, None).unwrap();
+    assert_eq!(log.len(), 1);
+    assert_eq!(log[0].sequence, 0);
+    assert_eq!(log[0].credential_id, Binary::new(first.id()));
+    assert_eq!(log[0].nonce, "1");
+    assert_eq!(log[0].block, BlockStamp::from(&env));
+    assert_eq!(log[0].data_hash, Binary::new(sha256(msg.data.as_slice())));
+}
+
+
+#[test]
+fn log_is_capped() {
+    let mut deps = mock_dependencies();
+    let env = mock_env();
+    credential_data(vec![cosmos_credential(&signing_key(1), data_to_sign(&env, 0))])
+        .save(&deps.api, &mut deps.storage, &env, &mock_info("alice", &[]), None).unwrap();
+
+    let total = AUDIT_CAPACITY + 5;
+    for nonce in 1..=total {
+        verify_actions(&deps.api, &mut deps.storage, &env, signed_msg(&signing_key(1), &env, nonce as u128), None).unwrap();
+    }
+    assert_eq!(audit_count(&deps.storage).unwrap(), total);
+
+    // the oldest records have been overwritten
+    let first_page = get_audit_log(&deps.storage, None, Some(3)).unwrap();
+    assert_eq!(first_page.iter().map(|r| r.sequence).collect::<Vec<_>>(), vec![5, 6, 7]);
+    assert_eq!(first_page[0].nonce, "6");
+
+    let last_page = get_audit_log(&deps.storage, Some(total - 3), None).unwrap();
+    assert_eq!(last_page.iter().map(|r| r.sequence).collect::<Vec<_>>(), vec![total - 2, total - 1]);
+}
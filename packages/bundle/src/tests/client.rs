@@ -0,0 +1,65 @@
+use cosmwasm_std::testing::{mock_dependencies, mock_env, mock_info};
+
+use crate::{client::{self, signed_data_msg, Ed25519Key, Secp256k1Key, Secp256r1Key}, verify_actions};
+use super::*;
+
+
+const MESSAGE: &[u8] = b"message to sign";
+
+
+#[test]
+fn signed_credentials_are_valid() {
+    let deps = mock_dependencies();
+    let k1 = Secp256k1Key::from_bytes(&[1; 32]).unwrap();
+    let r1 = Secp256r1Key::from_bytes(&[2; 32]).unwrap();
+    let ed = Ed25519Key::from_bytes(&[3; 32]).unwrap();
+
+    let credentials = vec![
+        k1.eth_personal_sign(MESSAGE).unwrap(),
+        k1.cosmos_arbitrary(MESSAGE, "cosmos").unwrap(),
+        k1.secp256k1(MESSAGE, None).unwrap(),
+        r1.secp256r1(MESSAGE).unwrap(),
+        r1.passkey(MESSAGE, "passkey-id", "https://example.com").unwrap(),
+        ed.ed25519(MESSAGE).unwrap(),
+    ];
+    for credential in credentials {
+        credential.verify_cosmwasm(&deps.api).unwrap();
+        assert_eq!(credential.message(), MESSAGE);
+    }
+
+    let Credential::CosmosArbitrary(cosmos) = k1.cosmos_arbitrary(MESSAGE, "cosmos").unwrap() else {
+        unreachable!()
+    };
+    assert_eq!(cosmos.hrp, Some("cosmos".into()));
+    assert_eq!(k1.cosmos_address("cosmos").unwrap(), pubkey_to_address(&k1.public_key(), "cosmos").unwrap());
+}
+
+
+#[test]
+fn signed_messages_authorise_actions() {
+    let mut deps = mock_dependencies();
+    let env = mock_env();
+    let chain_id = env.block.chain_id.clone();
+    let contract = env.contract.address.to_string();
+
+    let k1 = Secp256k1Key::from_bytes(&[1; 32]).unwrap();
+    let r1 = Secp256r1Key::from_bytes(&[2; 32]).unwrap();
+    let ed = Ed25519Key::from_bytes(&[3; 32]).unwrap();
+    let data = |nonce| client::data_to_sign(&chain_id, &contract, vec!["test"], nonce).unwrap();
+    assert_eq!(data(0), data_to_sign(&env, 0));
+
+    credential_data(vec![
+        k1.eth_personal_sign(data(0)).unwrap(),
+        r1.passkey(data(0), "passkey-id", "https://example.com").unwrap(),
+        ed.ed25519(data(0)).unwrap(),
+    ]).save(&deps.api, &mut deps.storage, &env, &mock_info("alice", &[]), None).unwrap();
+
+    let signed = [
+        k1.eth_personal_sign(data(1)).unwrap(),
+        r1.passkey(data(2), "passkey-id", "https://example.com").unwrap(),
+        ed.ed25519(data(3)).unwrap(),
+    ];
+    for credential in signed.iter() {
+        verify_actions(&deps.api, &mut deps.storage, &env, signed_data_msg(credential).unwrap(), None).unwrap();
+    }
+}
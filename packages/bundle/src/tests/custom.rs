@@ -0,0 +1,149 @@
+use cosmwasm_std::testing::{mock_dependencies, mock_env, mock_info};
+use cosmwasm_std::Api;
+use saa_common::{ensure, AuthError, CredentialName};
+
+use crate::{
+    get_all_credentials, verify_actions, 
+    CustomCredential, CustomVerifier, UpdateOperation, Verifiers
+};
+use super::*;
+
+
+/// Accepts the signatures equal to the hash of the id, message and the payload
+struct HashVerifier;
+
+/// Lenient verifier accepting any signature
+struct OpenVerifier;
+
+const VERIFIERS: Verifiers = &[&HashVerifier, &OpenVerifier];
+
+
+impl CustomVerifier for HashVerifier {
+    fn name(&self) -> &'static str {
+        "hash"
+    }
+
+    fn verify_cosmwasm(&self, _: &dyn Api, credential: &CustomCredential) -> Result<(), AuthError> {
+        let preimage = [
+            credential.id.as_slice(), 
+            credential.message.as_slice(), 
+            credential.payload.as_deref().unwrap_or_default()
+        ].concat();
+        ensure!(
+            sha256(&preimage) == credential.signature.to_vec(), 
+            AuthError::Signature("Hash mismatch".to_string())
+        );
+        Ok(())
+    }
+}
+
+
+impl CustomVerifier for OpenVerifier {
+    fn name(&self) -> &'static str {
+        "open"
+    }
+
+    fn verify_cosmwasm(&self, _: &dyn Api, _: &CustomCredential) -> Result<(), AuthError> {
+        Ok(())
+    }
+}
+
+
+fn hash_credential(name: &str, message: Binary, payload: Option<Binary>) -> CustomCredential {
+    let id = Binary::from(b"hash-id");
+    let preimage = [id.as_slice(), message.as_slice(), payload.as_deref().unwrap_or_default()].concat();
+    CustomCredential {
+        name: name.to_string(),
+        signature: sha256(&preimage).into(),
+        id,
+        message,
+        payload,
+    }
+}
+
+
+#[test]
+fn custom_credential_lifecycle() {
+    let mut deps = mock_dependencies();
+    let env = mock_env();
+
+    let credential = Credential::Custom(hash_credential("hash", data_to_sign(&env, 0), None));
+    credential_data(vec![credential.clone()])
+        .save(&deps.api, &mut deps.storage, &env, &mock_info("alice", &[]), Some(VERIFIERS))
+        .unwrap();
+
+    let stored = get_all_credentials(&deps.storage).unwrap();
+    assert_eq!(stored.credentials[0].1.name, CredentialName::Custom);
+    assert_eq!(stored.credentials[0].1.extension, Some(Binary::from(b"hash")));
+
+    // reconstructed from the stored tag with the payload passed as the extension
+    let payload = Binary::from(b"payload");
+    let custom = hash_credential("hash", data_to_sign(&env, 1), Some(payload.clone()));
+    let signed = SignedDataMsg {
+        payload: Some(AuthPayload {
+            credential_id: Some(custom.id.to_vec()),
+            hrp: None,
+            address: None,
+            extension: Some(payload),
+        }),
+        data: custom.message,
+        signature: custom.signature,
+    };
+    // the verifiers are only known to the calls that receive them
+    let err = verify_actions(&deps.api, &mut deps.storage, &env, signed.clone(), None);
+    assert_eq!(err.unwrap_err(), AuthError::UnsupportedCredential("hash".to_string()));
+
+    verify_actions(&deps.api, &mut deps.storage, &env, signed.clone(), Some(VERIFIERS)).unwrap();
+
+    let err = verify_actions(&deps.api, &mut deps.storage, &env, SignedDataMsg {
+        signature: Binary::from(b"forged"),
+        ..signed
+    }, Some(VERIFIERS));
+    assert_eq!(err.unwrap_err(), AuthError::Signature("Hash mismatch".to_string()));
+}
+
+
+#[test]
+fn unregistered_custom_credential() {
+    let mut deps = mock_dependencies();
+    let env = mock_env();
+
+    let credential = Credential::Custom(hash_credential("unknown", data_to_sign(&env, 0), None));
+    assert_eq!(
+        credential.verify_cosmwasm(&deps.api).unwrap_err(),
+        AuthError::UnsupportedCredential("unknown".to_string())
+    );
+    let err = credential_data(vec![credential])
+        .save(&deps.api, &mut deps.storage, &env, &mock_info("alice", &[]), Some(VERIFIERS));
+    assert_eq!(err.unwrap_err(), AuthError::UnsupportedCredential("unknown".to_string()));
+}
+
+
+#[test]
+fn custom_credential_must_match_the_stored_verifier() {
+    let mut deps = mock_dependencies();
+    let env = mock_env();
+    let info = mock_info("alice", &[]);
+
+    credential_data(vec![
+        Credential::Custom(hash_credential("hash", data_to_sign(&env, 0), None)),
+        cosmos_credential(&signing_key(1), data_to_sign(&env, 0)),
+    ]).save(&deps.api, &mut deps.storage, &env, &info, Some(VERIFIERS)).unwrap();
+
+    let cosmos_id = cosmos_credential(&signing_key(1), data_to_sign(&env, 0)).id();
+    let remove = || UpdateOperation::Remove(credential_data(vec![cosmos_credential(&signing_key(1), data_to_sign(&env, 1))]));
+
+    // the lenient verifier claiming the ids of credentials registered with other verifiers or kinds
+    for id in [b"hash-id".to_vec(), cosmos_id] {
+        let forged = CustomCredential { id: id.into(), ..hash_credential("open", data_to_sign(&env, 1), None) };
+        let err = credential_data(vec![Credential::Custom(forged)]).update(
+            remove(), &deps.api, &mut deps.storage, &env, &info, Some(VERIFIERS)
+        );
+        assert_eq!(err.unwrap_err(), AuthError::InvalidData("Credential isn't stored for the 'open' verifier".to_string()));
+    }
+
+    credential_data(vec![Credential::Custom(hash_credential("hash", data_to_sign(&env, 1), None))]).update(
+        remove(), &deps.api, &mut deps.storage, &env, &info, Some(VERIFIERS)
+    ).unwrap();
+    assert_eq!(get_all_credentials(&deps.storage).unwrap().credentials.len(), 1);
+}
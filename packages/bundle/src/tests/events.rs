@@ -0,0 +1,49 @@
+use cosmwasm_std::testing::{mock_dependencies, mock_env, mock_info};
+use cosmwasm_std::Event;
+
+use crate::{events::*, clear_credentials, verify_actions, UpdateOperation};
+use super::*;
+
+
+fn attr<'a>(event: &'a Event, key: &str) -> &'a str {
+    &event.attributes.iter().find(|a| a.key == key).unwrap().value
+}
+
+
+#[test]
+fn helpers_report_events() {
+    let mut deps = mock_dependencies();
+    let env = mock_env();
+    let info = mock_info("alice", &[]);
+    let first = cosmos_credential(&signing_key(1), data_to_sign(&env, 0));
+    let first_id = Binary::new(first.id()).to_base64();
+
+    let events = credential_data(vec![first]).save(&deps.api, &mut deps.storage, &env, &info, None).unwrap();
+    let types : Vec<&str> = events.iter().map(|e| e.ty.as_str()).collect();
+    assert_eq!(types, vec![NONCE_CONSUMED, CREDENTIAL_ADDED, PRIMARY_CHANGED]);
+    assert_eq!(attr(&events[0], "nonce"), "0");
+    assert_eq!(attr(&events[1], "id"), first_id);
+    assert_eq!(attr(&events[1], "name"), "cosmos_arbitrary");
+
+    let events = verify_actions(&deps.api, &mut deps.storage, &env, signed_msg(&signing_key(1), &env, 1), None).unwrap();
+    assert_eq!(events[0].ty, CREDENTIAL_USED);
+    assert_eq!(attr(&events[0], "id"), first_id);
+    assert_eq!(events[1].ty, NONCE_CONSUMED);
+    assert_eq!(attr(&events[1], "nonce"), "1");
+
+    let signer = credential_data(vec![cosmos_credential(&signing_key(1), data_to_sign(&env, 2))]);
+    let second = cosmos_credential(&signing_key(2), data_to_sign(&env, 2));
+    let events = signer.update(
+        UpdateOperation::Add(credential_data(vec![second.clone()])), &deps.api, &mut deps.storage, &env, &info, None
+    ).unwrap();
+    assert_eq!(events.len(), 2);
+    assert_eq!(attr(&events[0], "id"), Binary::new(second.id()).to_base64());
+
+    let signer = credential_data(vec![cosmos_credential(&signing_key(1), data_to_sign(&env, 3))]);
+    let events = signer.update(
+        UpdateOperation::Remove(credential_data(vec![cosmos_credential(&signing_key(2), data_to_sign(&env, 3))])), &deps.api, &mut deps.storage, &env, &info, None
+    ).unwrap();
+    assert_eq!(events[0].ty, CREDENTIAL_REMOVED);
+
+    assert_eq!(clear_credentials(&mut deps.storage).unwrap()[0].ty, CREDENTIALS_RESET);
+}
@@ -0,0 +1,27 @@
+use saa_common::CredentialName;
+
+use crate::library_info;
+
+
+#[test]
+fn enabled_features_are_reported() {
+    let info = library_info();
+    assert_eq!(info.name, "smart-account-auth");
+    assert_eq!(info.version, env!("CARGO_PKG_VERSION"));
+    assert!(info.replay);
+    assert_eq!(
+        info.features, 
+        ["ethereum", "cosmos", "passkeys", "curves", "ed25519", "custom", "siwe"].map(String::from)
+    );
+    assert_eq!(info.credentials, vec![
+        CredentialName::Caller,
+        CredentialName::EthPersonalSign,
+        CredentialName::CosmosArbitrary,
+        CredentialName::Passkey,
+        CredentialName::Secp256k1,
+        CredentialName::Secp256r1,
+        CredentialName::Ed25519,
+        CredentialName::Custom,
+        CredentialName::Siwe,
+    ]);
+}
@@ -0,0 +1,156 @@
+use cosmwasm_std::testing::{mock_dependencies, mock_env, mock_info, MockApi, MockQuerier, MockStorage};
+use cosmwasm_std::OwnedDeps;
+use saa_common::{storage::get_account_lock, AuthError, CredentialRole, LockPolicy};
+
+use crate::{
+    client::{signed_data_msg, Secp256r1Key}, lock_account, clear_credentials, verify_actions, 
+    CredentialSettings, UpdateOperation
+};
+use super::*;
+
+
+fn settings(role: CredentialRole) -> CredentialSettings {
+    CredentialSettings { role: Some(role), ..Default::default() }
+}
+
+
+/// Account with admin credentials under seeds 1 and 2, an executor under 3 and a guardian under 4
+fn setup() -> OwnedDeps<MockStorage, MockApi, MockQuerier> {
+    let mut deps = mock_dependencies();
+    let env = mock_env();
+    let data = CredentialData {
+        settings: Some(vec![
+            settings(CredentialRole::Admin),
+            settings(CredentialRole::Admin),
+            settings(CredentialRole::Executor),
+            settings(CredentialRole::Guardian),
+        ]),
+        ..credential_data((1..=4).map(|i| cosmos_credential(&signing_key(i), data_to_sign(&env, 0))).collect())
+    };
+    data.save(&deps.api, &mut deps.storage, &env, &mock_info("alice", &[]), None).unwrap();
+    deps
+}
+
+
+fn signers(seeds: &[u8], env: &Env, nonce: u128) -> CredentialData {
+    credential_data(seeds.iter().map(|i| cosmos_credential(&signing_key(*i), data_to_sign(env, nonce))).collect())
+}
+
+
+#[test]
+fn guardian_locks_account() {
+    let mut deps = setup();
+    let env = mock_env();
+    let info = mock_info("alice", &[]);
+
+    let err = verify_actions(&deps.api, &mut deps.storage, &env, signed_msg(&signing_key(4), &env, 1), None);
+    assert_eq!(err.unwrap_err(), AuthError::Unauthorized);
+
+    lock_account(&deps.api, &mut deps.storage, &env, signed_msg(&signing_key(4), &env, 1), None).unwrap();
+    let lock = get_account_lock(&deps.storage).unwrap().unwrap();
+    assert_eq!(lock.locked_by, signed_msg(&signing_key(4), &env, 1).payload.unwrap().credential_id.unwrap());
+
+    let err = verify_actions(&deps.api, &mut deps.storage, &env, signed_msg(&signing_key(1), &env, 2), None);
+    assert_eq!(err.unwrap_err(), AuthError::Locked);
+
+    let err = signers(&[1], &env, 2).update(
+        UpdateOperation::Remove(signers(&[3], &env, 2)), &deps.api, &mut deps.storage, &env, &info, None
+    );
+    assert_eq!(err.unwrap_err(), AuthError::Locked);
+
+    let err = lock_account(&deps.api, &mut deps.storage, &env, signed_msg(&signing_key(3), &env, 2), None);
+    assert_eq!(err.unwrap_err(), AuthError::Locked);
+}
+
+
+#[test]
+fn unlock_requires_multiple_admins() {
+    let mut deps = setup();
+    let env = mock_env();
+    lock_account(&deps.api, &mut deps.storage, &env, signed_msg(&signing_key(3), &env, 1), None).unwrap();
+
+    let err = signers(&[1], &env, 2).unlock(&deps.api, &mut deps.storage, &env, None);
+    assert_eq!(err.unwrap_err(), AuthError::Unauthorized);
+
+    let err = signers(&[1, 3], &env, 2).unlock(&deps.api, &mut deps.storage, &env, None);
+    assert_eq!(err.unwrap_err(), AuthError::Unauthorized);
+
+    signers(&[1, 2], &env, 2).unlock(&deps.api, &mut deps.storage, &env, None).unwrap();
+    assert_eq!(get_account_lock(&deps.storage).unwrap(), None);
+
+    verify_actions(&deps.api, &mut deps.storage, &env, signed_msg(&signing_key(3), &env, 3), None).unwrap();
+}
+
+
+#[test]
+fn single_admin_unlocks_after_delay() {
+    let mut deps = setup();
+    let mut env = mock_env();
+    saa_common::storage::set_lock_policy(&mut deps.storage, &LockPolicy { threshold: 3, delay: 3_600 }).unwrap();
+    lock_account(&deps.api, &mut deps.storage, &env, signed_msg(&signing_key(1), &env, 1), None).unwrap();
+
+    let err = signers(&[1, 2], &env, 2).unlock(&deps.api, &mut deps.storage, &env, None);
+    assert_eq!(err.unwrap_err(), AuthError::Unauthorized);
+
+    env.block.time = env.block.time.plus_seconds(3_600);
+    signers(&[2], &env, 2).unlock(&deps.api, &mut deps.storage, &env, None).unwrap();
+    verify_actions(&deps.api, &mut deps.storage, &env, signed_msg(&signing_key(1), &env, 3), None).unwrap();
+}
+
+
+#[test]
+fn unlock_delay_can_be_unreachable() {
+    let mut deps = setup();
+    let mut env = mock_env();
+    saa_common::storage::set_lock_policy(&mut deps.storage, &LockPolicy { threshold: 2, delay: u64::MAX }).unwrap();
+    lock_account(&deps.api, &mut deps.storage, &env, signed_msg(&signing_key(1), &env, 1), None).unwrap();
+
+    env.block.time = env.block.time.plus_seconds(1_000_000_000);
+    let err = signers(&[2], &env, 2).unlock(&deps.api, &mut deps.storage, &env, None);
+    assert_eq!(err.unwrap_err(), AuthError::Unauthorized);
+    signers(&[1, 2], &env, 2).unlock(&deps.api, &mut deps.storage, &env, None).unwrap();
+}
+
+
+#[test]
+fn unlock_checks_stored_extensions() {
+    let mut deps = mock_dependencies();
+    let env = mock_env();
+    let r1 = Secp256r1Key::from_bytes(&[5; 32]).unwrap();
+    let passkey = |nonce, origin| r1.passkey(data_to_sign(&env, nonce), "passkey-id", origin).unwrap();
+
+    CredentialData {
+        settings: Some(vec![settings(CredentialRole::Admin), settings(CredentialRole::Admin)]),
+        ..credential_data(vec![
+            cosmos_credential(&signing_key(1), data_to_sign(&env, 0)),
+            passkey(0, "https://example.com"),
+        ])
+    }.save(&deps.api, &mut deps.storage, &env, &mock_info("alice", &[]), None).unwrap();
+    lock_account(&deps.api, &mut deps.storage, &env, signed_msg(&signing_key(1), &env, 1), None).unwrap();
+
+    // a valid assertion made at a different origin
+    let unlock = |origin| CredentialData {
+        credentials: vec![cosmos_credential(&signing_key(1), data_to_sign(&env, 2)), passkey(2, origin)],
+        ..credential_data(vec![])
+    };
+    let err = unlock("https://evil.com").unlock(&deps.api, &mut deps.storage, &env, None);
+    assert_eq!(err.unwrap_err(), AuthError::OriginMismatch);
+
+    unlock("https://example.com").unlock(&deps.api, &mut deps.storage, &env, None).unwrap();
+    verify_actions(
+        &deps.api, &mut deps.storage, &env, signed_data_msg(&passkey(3, "https://example.com")).unwrap(), None
+    ).unwrap();
+}
+
+
+#[test]
+fn reset_lifts_the_lock() {
+    let mut deps = setup();
+    let env = mock_env();
+    saa_common::storage::set_lock_policy(&mut deps.storage, &LockPolicy { threshold: 3, delay: 3_600 }).unwrap();
+    lock_account(&deps.api, &mut deps.storage, &env, signed_msg(&signing_key(3), &env, 1), None).unwrap();
+
+    clear_credentials(&mut deps.storage).unwrap();
+    assert_eq!(get_account_lock(&deps.storage).unwrap(), None);
+    assert_eq!(saa_common::storage::get_lock_policy(&deps.storage).unwrap(), LockPolicy::default());
+}
@@ -0,0 +1,89 @@
+use cosmwasm_std::testing::{mock_dependencies, mock_env, mock_info};
+use saa_common::{messages::SignedDataMsg, BlockStamp, Verifiable};
+
+use crate::{get_all_credentials, verify_actions, CredentialSettings, UpdateOperation};
+use super::*;
+
+
+#[test]
+fn labels_and_usage_are_stored() {
+    let mut deps = mock_dependencies();
+    let mut env = mock_env();
+    let info = mock_info("alice", &[]);
+
+    let first = cosmos_credential(&signing_key(1), data_to_sign(&env, 0));
+    let data = CredentialData {
+        settings: Some(vec![CredentialSettings { label: Some("Ledger".into()), ..Default::default() }]),
+        ..credential_data(vec![first.clone()])
+    };
+    data.save(&deps.api, &mut deps.storage, &env, &info, None).unwrap();
+
+    let stored = get_all_credentials(&deps.storage).unwrap().credentials;
+    assert_eq!(stored.len(), 1);
+    assert_eq!(stored[0].1.label, Some("Ledger".into()));
+    assert_eq!(stored[0].1.created_at, Some(BlockStamp::from(&env)));
+    assert_eq!(stored[0].1.last_used, None);
+
+    env.block.height += 10;
+    let Credential::CosmosArbitrary(signer) = cosmos_credential(&signing_key(1), data_to_sign(&env, 1)) else {
+        unreachable!()
+    };
+    verify_actions(&deps.api, &mut deps.storage, &env, SignedDataMsg {
+        data: signer.message,
+        signature: signer.signature,
+        payload: None,
+    }, None).unwrap();
+
+    let stored = get_all_credentials(&deps.storage).unwrap().credentials;
+    assert_eq!(stored[0].1.last_used, Some(BlockStamp::from(&env)));
+    assert_ne!(stored[0].1.created_at, stored[0].1.last_used);
+}
+
+
+#[test]
+fn labels_on_added_credentials() {
+    let mut deps = mock_dependencies();
+    let env = mock_env();
+    let info = mock_info("alice", &[]);
+
+    let first = cosmos_credential(&signing_key(1), data_to_sign(&env, 0));
+    credential_data(vec![first]).save(&deps.api, &mut deps.storage, &env, &info, None).unwrap();
+
+    let signer = credential_data(vec![cosmos_credential(&signing_key(1), data_to_sign(&env, 1))]);
+    let second = cosmos_credential(&signing_key(2), data_to_sign(&env, 1));
+    let new = CredentialData {
+        settings: Some(vec![CredentialSettings { label: Some("Phone".into()), ..Default::default() }]),
+        ..credential_data(vec![second.clone()])
+    };
+    signer.update(
+        UpdateOperation::Add(new), &deps.api, &mut deps.storage, &env, &info, None
+    ).unwrap();
+
+    let stored = get_all_credentials(&deps.storage).unwrap().credentials;
+    assert_eq!(stored.len(), 2);
+    let added = stored.iter().find(|(id, _)| *id == second.id()).unwrap();
+    assert_eq!(added.1.label, Some("Phone".into()));
+    assert_eq!(added.1.created_at, Some(BlockStamp::from(&env)));
+}
+
+
+#[test]
+fn too_long_labels_are_rejected() {
+    let env = mock_env();
+    let data = CredentialData {
+        settings: Some(vec![CredentialSettings { label: Some("a".repeat(crate::MAX_LABEL_LENGTH + 1)), ..Default::default() }]),
+        ..credential_data(vec![cosmos_credential(&signing_key(1), data_to_sign(&env, 0))])
+    };
+    assert!(data.validate().is_err());
+}
+
+
+#[test]
+fn labels_are_measured_in_characters() {
+    let env = mock_env();
+    let data = CredentialData {
+        settings: Some(vec![CredentialSettings { label: Some("ключ".repeat(crate::MAX_LABEL_LENGTH / 4)), ..Default::default() }]),
+        ..credential_data(vec![cosmos_credential(&signing_key(1), data_to_sign(&env, 0))])
+    };
+    assert!(data.validate().is_ok());
+}
@@ -0,0 +1,36 @@
+use cosmwasm_std::testing::{mock_dependencies, mock_env, mock_info};
+use saa_common::{storage::AccountStorage, AuthError};
+
+use crate::{client::account_data_to_sign, verify_actions};
+use super::*;
+
+
+fn account_data(env: &Env, account_id: &str, nonce: u128) -> Binary {
+    account_data_to_sign(
+        &env.block.chain_id, env.contract.address.as_str(), account_id, vec!["test"], nonce
+    ).unwrap()
+}
+
+
+#[test]
+fn signatures_are_bound_to_the_account() {
+    let mut deps = mock_dependencies();
+    let env = mock_env();
+    let key = signing_key(1);
+
+    // same credential registered for two accounts of the registry
+    for account in ["alice", "bob"] {
+        let data = credential_data(vec![cosmos_credential(&key, account_data(&env, account, 0))]);
+        data.save(&deps.api, &mut AccountStorage::new(&mut deps.storage, account), &env, &mock_info("registry", &[]), None).unwrap();
+    }
+
+    // both accounts are at the same nonce but the message of alice can't be used for bob
+    let signed = signed_data(&key, account_data(&env, "alice", 1));
+    let err = verify_actions(&deps.api, &mut AccountStorage::new(&mut deps.storage, "bob"), &env, signed.clone(), None);
+    assert_eq!(err.unwrap_err(), AuthError::AccountMismatch);
+    verify_actions(&deps.api, &mut AccountStorage::new(&mut deps.storage, "alice"), &env, signed, None).unwrap();
+
+    // messages without an account are only valid for the storage of the contract itself
+    let err = verify_actions(&deps.api, &mut AccountStorage::new(&mut deps.storage, "bob"), &env, signed_msg(&key, &env, 1), None);
+    assert_eq!(err.unwrap_err(), AuthError::AccountMismatch);
+}
@@ -0,0 +1,75 @@
+use cosmwasm_std::testing::{mock_dependencies, mock_env, mock_info, MockApi, MockQuerier, MockStorage};
+use cosmwasm_std::OwnedDeps;
+use saa_common::{AuthError, CredentialRole};
+
+use crate::{verify_actions, verify_queries, CredentialSettings, UpdateOperation};
+use super::*;
+
+
+fn settings(role: CredentialRole) -> CredentialSettings {
+    CredentialSettings { role: Some(role), ..Default::default() }
+}
+
+
+/// Account with an admin, an executor and a viewer credentials under seeds 1, 2 and 3
+fn setup() -> OwnedDeps<MockStorage, MockApi, MockQuerier> {
+    let mut deps = mock_dependencies();
+    let env = mock_env();
+    let data = CredentialData {
+        settings: Some(vec![
+            settings(CredentialRole::Admin),
+            settings(CredentialRole::Executor),
+            settings(CredentialRole::Viewer),
+        ]),
+        ..credential_data((1..=3).map(|i| cosmos_credential(&signing_key(i), data_to_sign(&env, 0))).collect())
+    };
+    data.save(&deps.api, &mut deps.storage, &env, &mock_info("alice", &[]), None).unwrap();
+    deps
+}
+
+
+#[test]
+fn roles_of_signed_actions() {
+    let mut deps = setup();
+    let env = mock_env();
+
+    verify_actions(&deps.api, &mut deps.storage, &env, signed_msg(&signing_key(2), &env, 1), None).unwrap();
+    verify_actions(&deps.api, &mut deps.storage, &env, signed_msg(&signing_key(1), &env, 2), None).unwrap();
+
+    let err = verify_actions(&deps.api, &mut deps.storage, &env, signed_msg(&signing_key(3), &env, 3), None);
+    assert_eq!(err.unwrap_err(), AuthError::Unauthorized);
+
+    verify_queries(&deps.api, &deps.storage, &env, signed_msg(&signing_key(3), &env, 3), None).unwrap();
+}
+
+
+#[test]
+fn only_admins_update_credentials() {
+    let mut deps = setup();
+    let env = mock_env();
+    let info = mock_info("alice", &[]);
+    let new = || credential_data(vec![cosmos_credential(&signing_key(4), data_to_sign(&env, 1))]);
+
+    for seed in [2, 3] {
+        let signer = credential_data(vec![cosmos_credential(&signing_key(seed), data_to_sign(&env, 1))]);
+        let err = signer.update(UpdateOperation::Add(new()), &deps.api, &mut deps.storage, &env, &info, None);
+        assert_eq!(err.unwrap_err(), AuthError::Unauthorized);
+    }
+
+    let signer = credential_data(vec![cosmos_credential(&signing_key(1), data_to_sign(&env, 1))]);
+    signer.update(UpdateOperation::Add(new()), &deps.api, &mut deps.storage, &env, &info, None).unwrap();
+}
+
+
+#[test]
+fn primary_must_be_admin() {
+    let mut deps = mock_dependencies();
+    let env = mock_env();
+    let data = CredentialData {
+        primary_index: Some(0),
+        settings: Some(vec![settings(CredentialRole::Executor)]),
+        ..credential_data(vec![cosmos_credential(&signing_key(1), data_to_sign(&env, 0))])
+    };
+    let err = data.save(&deps.api, &mut deps.storage, &env, &mock_info("alice", &[]), None);
+    assert_eq!(err.unwrap_err(), AuthError::Unauthorized);
+}
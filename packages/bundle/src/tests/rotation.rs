@@ -0,0 +1,110 @@
+use cosmwasm_std::testing::{mock_dependencies, mock_env, mock_info, MockApi, MockQuerier, MockStorage};
+use cosmwasm_std::OwnedDeps;
+use saa_common::{AuthError, CredentialRole};
+
+use crate::{get_all_credentials, verify_actions, CredentialSettings, UpdateOperation};
+use super::*;
+
+
+/// Account with an admin under seed 1 that is the verifying credential, 
+/// another admin under seed 2 and an executor under seed 3
+fn setup() -> OwnedDeps<MockStorage, MockApi, MockQuerier> {
+    let mut deps = mock_dependencies();
+    let env = mock_env();
+    let data = CredentialData {
+        settings: Some(vec![
+            CredentialSettings::default(),
+            CredentialSettings::default(),
+            CredentialSettings { role: Some(CredentialRole::Executor), ..Default::default() },
+        ]),
+        ..credential_data((1..=3).map(|i| cosmos_credential(&signing_key(i), data_to_sign(&env, 0))).collect())
+    };
+    data.save(&deps.api, &mut deps.storage, &env, &mock_info("alice", &[]), None).unwrap();
+    deps
+}
+
+
+fn signer(seed: u8, env: &Env, nonce: u128) -> CredentialData {
+    credential_data(vec![cosmos_credential(&signing_key(seed), data_to_sign(env, nonce))])
+}
+
+
+fn id(seed: u8) -> Binary {
+    cosmos_credential(&signing_key(seed), data_to_sign(&mock_env(), 0)).id().into()
+}
+
+
+#[test]
+fn set_primary_credential() {
+    let mut deps = setup();
+    let env = mock_env();
+    let info = mock_info("alice", &[]);
+
+    let err = signer(1, &env, 1).update(
+        UpdateOperation::SetPrimary(id(3)), &deps.api, &mut deps.storage, &env, &info, None
+    );
+    assert_eq!(err.unwrap_err(), AuthError::Unauthorized);
+
+    let err = signer(1, &env, 1).update(
+        UpdateOperation::SetPrimary(id(4)), &deps.api, &mut deps.storage, &env, &info, None
+    );
+    assert_eq!(err.unwrap_err(), AuthError::NotFound);
+
+    signer(1, &env, 1).update(
+        UpdateOperation::SetPrimary(id(2)), &deps.api, &mut deps.storage, &env, &info, None
+    ).unwrap();
+    assert_eq!(get_all_credentials(&deps.storage).unwrap().verifying_id, id(2));
+
+    // the previous primary can be removed now
+    signer(2, &env, 2).update(
+        UpdateOperation::Remove(signer(1, &env, 2)), &deps.api, &mut deps.storage, &env, &info, None
+    ).unwrap();
+    assert_eq!(get_all_credentials(&deps.storage).unwrap().credentials.len(), 2);
+}
+
+
+#[test]
+fn replace_verifying_credential() {
+    let mut deps = setup();
+    let env = mock_env();
+    let info = mock_info("alice", &[]);
+
+    signer(1, &env, 1).update(
+        UpdateOperation::Replace { add: signer(4, &env, 1), remove: vec![id(1)] },
+        &deps.api, &mut deps.storage, &env, &info, None
+    ).unwrap();
+
+    let account = get_all_credentials(&deps.storage).unwrap();
+    assert_eq!(account.verifying_id, id(4));
+    assert!(account.credentials.iter().all(|(cid, _)| *cid != id(1)));
+
+    let err = verify_actions(&deps.api, &mut deps.storage, &env, signed_msg(&signing_key(1), &env, 2), None);
+    assert_eq!(err.unwrap_err(), AuthError::NotFound);
+    verify_actions(&deps.api, &mut deps.storage, &env, signed_msg(&signing_key(4), &env, 2), None).unwrap();
+}
+
+
+#[test]
+fn replace_requires_new_admin() {
+    let mut deps = setup();
+    let env = mock_env();
+    let info = mock_info("alice", &[]);
+
+    let add = CredentialData {
+        settings: Some(vec![CredentialSettings { role: Some(CredentialRole::Executor), ..Default::default() }]),
+        ..signer(4, &env, 1)
+    };
+    let err = signer(1, &env, 1).update(
+        UpdateOperation::Replace { add, remove: vec![id(1)] },
+        &deps.api, &mut deps.storage, &env, &info, None
+    );
+    assert_eq!(err.unwrap_err(), AuthError::NoVerifying);
+
+    // other credentials can be replaced without touching the verifying one
+    let mut deps = setup();
+    signer(1, &env, 1).update(
+        UpdateOperation::Replace { add: signer(4, &env, 1), remove: vec![id(3)] },
+        &deps.api, &mut deps.storage, &env, &info, None
+    ).unwrap();
+    assert_eq!(get_all_credentials(&deps.storage).unwrap().verifying_id, id(1));
+}
@@ -0,0 +1,36 @@
+use crate::schema::{schemas, typescript};
+
+
+const GENERATED_TS: &str = include_str!("../../../../package/src/generated.ts");
+
+
+#[test]
+fn typescript_definitions_are_up_to_date() {
+    assert!(
+        GENERATED_TS == typescript(),
+        "package/src/generated.ts is outdated. Run `cargo run -p smart-account-auth --example schema --features typescript,curves,spending,custom,siwe`"
+    );
+}
+
+
+#[test]
+fn json_schemas_are_up_to_date() {
+    let dir = std::path::Path::new(env!("CARGO_MANIFEST_DIR")).join("schema");
+    for (name, schema) in schemas() {
+        let stored = std::fs::read_to_string(dir.join(format!("{name}.json"))).unwrap();
+        let expected = serde_json::to_string_pretty(&schema).unwrap() + "\n";
+        assert_eq!(stored, expected, "schema/{name}.json is outdated");
+    }
+}
+
+
+#[test]
+fn generics_and_options_are_rendered() {
+    let ts = typescript();
+    assert!(ts.contains("export interface MsgDataToSign<M = string> {\n"));
+    assert!(ts.contains("    messages: M[];\n"));
+    assert!(ts.contains("    payload?: AuthPayload | null;\n"));
+    assert!(ts.contains("    crossOrigin: boolean;\n"));
+    assert!(ts.contains("    | { replace: { add: CredentialData; remove: Binary[] } }"));
+    assert!(!ts.contains("__TypeParam"));
+}
@@ -0,0 +1,245 @@
+use core::fmt::Debug;
+use cosmwasm_std::Uint128;
+use serde::{de::DeserializeOwned, Serialize};
+use saa_auth::passkey::{PasskeyExtension, PasskeyPayload};
+use saa_common::{
+    audit::AuditRecord, spending::{SpendRecord, SpendingLimit},
+    messages::AccountCredentials, AccountLock, AuthError, BlockStamp,
+    CredentialInfo, CredentialName, CredentialRole, LockPolicy,
+};
+
+use crate::{
+    Caller, ClientData, CosmosArbitrary, CredentialSettings, Ed25519, EthPersonalSign,
+    PasskeyCredential, Secp256k1, Secp256r1, UpdateOperation,
+};
+use super::*;
+
+
+/// Encode and decode the value with a human readable and two binary formats
+fn roundtrip<T: Serialize + DeserializeOwned + PartialEq + Debug>(value: &T) {
+    let json = serde_json::to_vec(value).unwrap();
+    assert_eq!(&serde_json::from_slice::<T>(&json).unwrap(), value, "serde_json");
+
+    let bin = bincode::serialize(value).unwrap();
+    assert_eq!(&bincode::deserialize::<T>(&bin).unwrap(), value, "bincode");
+
+    let mut cbor = Vec::new();
+    ciborium::into_writer(value, &mut cbor).unwrap();
+    assert_eq!(&ciborium::from_reader::<T, _>(cbor.as_slice()).unwrap(), value, "cbor");
+}
+
+
+fn bytes(len: usize, seed: u8) -> Binary {
+    (0..len).map(|i| seed.wrapping_add(i as u8)).collect::<Vec<u8>>().into()
+}
+
+
+fn stamp() -> BlockStamp {
+    BlockStamp { height: 12_345, time: 1_700_000_000 }
+}
+
+
+fn passkey(other_keys: bool) -> PasskeyCredential {
+    PasskeyCredential {
+        id: "passkey-id".into(),
+        signature: bytes(64, 1),
+        authenticator_data: bytes(37, 2),
+        client_data: ClientData::new(
+            "webauthn.get".into(), "Y2hhbGxlbmdl".into(), "https://localhost".into(), false, other_keys
+        ),
+        user_handle: other_keys.then(|| "user".into()),
+        pubkey: Some(bytes(65, 3)),
+    }
+}
+
+
+fn credentials() -> Vec<Credential> {
+    vec![
+        Credential::Caller(Caller { id: b"cosmos1caller".to_vec() }),
+        Credential::EthPersonalSign(EthPersonalSign {
+            message: bytes(32, 4),
+            signature: bytes(65, 5),
+            signer: "0x7cd2b4aa6e4e3b4f8c8fd4f3a3f5d6a1c25e9d3b".into(),
+        }),
+        Credential::CosmosArbitrary(CosmosArbitrary {
+            pubkey: bytes(33, 6),
+            message: bytes(48, 7),
+            signature: bytes(64, 8),
+            hrp: Some("cosmos".into()),
+        }),
+        Credential::Passkey(passkey(false)),
+        Credential::Passkey(passkey(true)),
+        Credential::Secp256k1(Secp256k1 {
+            pubkey: bytes(33, 9),
+            message: Binary::default(),
+            signature: bytes(64, 10),
+            hrp: None,
+        }),
+        Credential::Secp256r1(Secp256r1 {
+            pubkey: bytes(65, 11),
+            message: bytes(1, 12),
+            signature: bytes(64, 13),
+        }),
+        Credential::Ed25519(Ed25519 {
+            pubkey: bytes(32, 14),
+            message: bytes(100, 15),
+            signature: bytes(64, 16),
+        }),
+    ]
+}
+
+
+fn data() -> CredentialData {
+    CredentialData {
+        credentials: credentials(),
+        with_caller: Some(true),
+        primary_index: Some(2),
+        settings: Some(vec![
+            CredentialSettings::default(),
+            CredentialSettings {
+                label: Some("YubiKey".into()),
+                role: Some(CredentialRole::Executor),
+                spending_limits: Some(vec![SpendingLimit {
+                    denom: "uatom".into(),
+                    amount: Uint128::new(1_000_000),
+                    period: 86_400,
+                }]),
+            },
+        ]),
+    }
+}
+
+
+#[test]
+fn binary_is_bytes_in_binary_formats() {
+    let binary = bytes(4, 0xfa);
+
+    assert_eq!(serde_json::to_string(&binary).unwrap(), "\"+vv8/Q==\"");
+    // u64 length prefix followed by the raw bytes
+    assert_eq!(bincode::serialize(&binary).unwrap(), [&4u64.to_le_bytes()[..], &[0xfa, 0xfb, 0xfc, 0xfd]].concat());
+
+    let mut cbor = Vec::new();
+    ciborium::into_writer(&binary, &mut cbor).unwrap();
+    // major type 2 (byte string) of length 4
+    assert_eq!(cbor, [0x44, 0xfa, 0xfb, 0xfc, 0xfd]);
+
+    // formats without a type for bytes encode them as sequences
+    let from_seq : Binary = ciborium::from_reader([0x82, 0x01, 0x02].as_slice()).unwrap();
+    assert_eq!(from_seq, Binary::from([1, 2]));
+
+    roundtrip(&Binary::default());
+    roundtrip(&binary);
+}
+
+
+#[test]
+fn client_data_keeps_browser_format_in_json() {
+    let client_data = passkey(false).client_data;
+    assert_eq!(
+        serde_json::to_string(&client_data).unwrap(),
+        r#"{"type":"webauthn.get","challenge":"Y2hhbGxlbmdl","origin":"https://localhost","crossOrigin":false}"#
+    );
+    assert_eq!(to_json_binary(&client_data).unwrap(), serde_json::to_vec(&client_data).unwrap());
+    roundtrip(&client_data);
+    roundtrip(&passkey(true).client_data);
+}
+
+
+#[test]
+fn credentials_roundtrip() {
+    for credential in credentials() {
+        roundtrip(&credential);
+    }
+    roundtrip(&passkey(true));
+    roundtrip(&PasskeyExtension {
+        origin: "https://localhost".into(),
+        pubkey: Some(bytes(65, 17)),
+        cross_origin: true,
+        user_handle: None,
+    });
+    roundtrip(&PasskeyPayload {
+        authenticator_data: bytes(37, 18),
+        pubkey: None,
+        other_keys: Some(true),
+    });
+}
+
+
+#[test]
+fn credential_data_roundtrip() {
+    let data = data();
+    roundtrip(&data);
+    roundtrip(&credential_data(vec![]));
+    roundtrip(&CredentialSettings::default());
+
+    roundtrip(&UpdateOperation::Add(data.clone()));
+    roundtrip(&UpdateOperation::Remove(data.clone()));
+    roundtrip(&UpdateOperation::Replace { add: data, remove: vec![bytes(20, 19), Binary::default()] });
+    roundtrip(&UpdateOperation::<CredentialData>::SetPrimary(bytes(33, 20)));
+}
+
+
+#[test]
+fn messages_roundtrip() {
+    roundtrip(&AuthPayload::<Binary> {
+        hrp: Some("cosmos".into()),
+        address: None,
+        credential_id: Some(bytes(33, 21).to_vec()),
+        extension: Some(bytes(8, 22)),
+    });
+    roundtrip(&MsgDataToSign {
+        chain_id: "cosmoshub-4".into(),
+        contract_address: "cosmos1contract".into(),
+        messages: vec!["{\"execute\":{}}".to_string()],
+        nonce: "7".into(),
+        account_id: Some("alice".into()),
+    });
+    roundtrip(&SignedDataMsg {
+        data: bytes(120, 23),
+        signature: bytes(64, 24),
+        payload: None,
+    });
+
+    let info = CredentialInfo {
+        name: CredentialName::Passkey,
+        hrp: None,
+        extension: Some(bytes(70, 25)),
+        label: Some("MacBook Touch ID".into()),
+        created_at: Some(stamp()),
+        last_used: None,
+        role: Some(CredentialRole::Guardian),
+    };
+    roundtrip(&info);
+    roundtrip(&AccountCredentials {
+        credentials: vec![(bytes(33, 26), info)],
+        verifying_id: bytes(33, 26),
+        native_caller: false,
+    });
+}
+
+
+#[test]
+fn account_state_roundtrip() {
+    for name in [
+        CredentialName::Caller, CredentialName::CosmosArbitrary, CredentialName::EthPersonalSign,
+        CredentialName::Passkey, CredentialName::Secp256k1, CredentialName::Secp256r1, CredentialName::Ed25519,
+    ] {
+        roundtrip(&name);
+    }
+    for role in [CredentialRole::Admin, CredentialRole::Executor, CredentialRole::Viewer, CredentialRole::Guardian] {
+        roundtrip(&role);
+    }
+    roundtrip(&stamp());
+    roundtrip(&AccountLock { locked_by: bytes(33, 27), locked_at: stamp() });
+    roundtrip(&LockPolicy::default());
+    roundtrip(&SpendRecord { time: 1_700_000_000, amount: Uint128::MAX });
+    roundtrip(&AuditRecord {
+        sequence: 3,
+        credential_id: bytes(20, 28),
+        nonce: "3".into(),
+        block: stamp(),
+        data_hash: bytes(32, 29),
+    });
+    roundtrip(&AuthError::InvalidLength(32, 31));
+    roundtrip(&AuthError::generic("error"));
+}
@@ -0,0 +1,95 @@
+use cosmwasm_std::testing::{mock_dependencies, mock_env, mock_info};
+use saa_common::{AuthError, CredentialName, CredentialRole};
+
+use crate::{
+    client::{signed_data_msg, Secp256k1Key}, get_all_credentials, lock_account, 
+    verify_actions, verify_queries, CredentialSettings
+};
+use super::*;
+
+
+/// Message for the account of the mocked contract. The mocked block time is in October 2019
+fn siwe_message(key: &Secp256k1Key, env: &Env, domain: &str, nonce: u128, expiration: &str) -> String {
+    format!(
+        "{domain} wants you to sign in with your Ethereum account:\n{}\n\nSign in to the smart account\n\n\
+        URI: https://{domain}\nVersion: 1\nChain ID: 1\nNonce: {nonce:08}\nIssued At: 2019-10-01T00:00:00Z\n\
+        Expiration Time: {expiration}\nResources:\n- cosmos:{}:{}",
+        key.eth_address(), env.block.chain_id, env.contract.address
+    )
+}
+
+
+#[test]
+fn siwe_credential_lifecycle() {
+    let mut deps = mock_dependencies();
+    let env = mock_env();
+    let key = Secp256k1Key::from_bytes(&[7; 32]).unwrap();
+
+    let credential = key.siwe(&siwe_message(&key, &env, "app.example", 0, "2030-01-01T00:00:00Z")).unwrap();
+    assert_eq!(credential.id(), key.eth_address().into_bytes());
+    credential_data(vec![credential])
+        .save(&deps.api, &mut deps.storage, &env, &mock_info("alice", &[]), None)
+        .unwrap();
+
+    let stored = get_all_credentials(&deps.storage).unwrap();
+    assert_eq!(stored.credentials[0].1.name, CredentialName::Siwe);
+
+    let sign = |domain: &str, nonce: u128, expiration: &str| signed_data_msg(
+        &key.siwe(&siwe_message(&key, &env, domain, nonce, expiration)).unwrap()
+    ).unwrap();
+
+    assert_eq!(
+        verify_queries(&deps.api, &deps.storage, &env, sign("evil.example", 1, "2030-01-01T00:00:00Z"), None),
+        Err(AuthError::OriginMismatch)
+    );
+    assert_eq!(
+        verify_queries(&deps.api, &deps.storage, &env, sign("app.example", 1, "2019-10-02T00:00:00Z"), None),
+        Err(AuthError::Expired)
+    );
+    assert_eq!(
+        verify_queries(&deps.api, &deps.storage, &env, sign("app.example", 2, "2030-01-01T00:00:00Z"), None),
+        Err(AuthError::DifferentNonce)
+    );
+    verify_queries(&deps.api, &deps.storage, &env, sign("app.example", 1, "2030-01-01T00:00:00Z"), None).unwrap();
+
+    // the message doesn't list the actions
+    assert_eq!(
+        verify_actions(&deps.api, &mut deps.storage, &env, sign("app.example", 1, "2030-01-01T00:00:00Z"), None),
+        Err(AuthError::Unauthorized)
+    );
+    assert_eq!(
+        lock_account(&deps.api, &mut deps.storage, &env, sign("app.example", 1, "2030-01-01T00:00:00Z"), None),
+        Err(AuthError::Unauthorized)
+    );
+}
+
+
+#[test]
+fn siwe_message_must_match_the_credential() {
+    let mut deps = mock_dependencies();
+    let env = mock_env();
+    let admin = Secp256k1Key::from_bytes(&[7; 32]).unwrap();
+    let viewer = Secp256k1Key::from_bytes(&[8; 32]).unwrap();
+
+    let register = |key: &Secp256k1Key| key.siwe(&siwe_message(key, &env, "app.example", 0, "2030-01-01T00:00:00Z")).unwrap();
+    let role = |role| CredentialSettings { role: Some(role), ..Default::default() };
+    CredentialData {
+        settings: Some(vec![role(CredentialRole::Admin), role(CredentialRole::Viewer)]),
+        ..credential_data(vec![register(&admin), register(&viewer)])
+    }
+    .save(&deps.api, &mut deps.storage, &env, &mock_info("alice", &[]), None)
+    .unwrap();
+
+    let signed = signed_data_msg(
+        &viewer.siwe(&siwe_message(&viewer, &env, "app.example", 1, "2030-01-01T00:00:00Z")).unwrap()
+    ).unwrap();
+    verify_queries(&deps.api, &deps.storage, &env, signed.clone(), None).unwrap();
+
+    // the viewer can't pass its own message as the one of the admin
+    let mut payload = signed.payload.clone().unwrap();
+    payload.credential_id = Some(admin.eth_address().to_lowercase().into_bytes());
+    assert_eq!(
+        verify_queries(&deps.api, &deps.storage, &env, SignedDataMsg { payload: Some(payload), ..signed }, None),
+        Err(AuthError::InvalidData("Address of the message doesn't match the credential".to_string()))
+    );
+}
@@ -0,0 +1,210 @@
+use cosmwasm_std::testing::{mock_dependencies, mock_env, mock_info, MockApi, MockQuerier, MockStorage};
+use cosmwasm_std::{coins, BankMsg, CosmosMsg, Empty, Env, Event, OwnedDeps, Uint128, WasmMsg};
+use k256::ecdsa::SigningKey;
+use saa_common::{AuthError, CredentialRole};
+
+use crate::{verify_actions, verify_cosmos_msgs, CredentialSettings, SpendingLimit, UpdateOperation};
+use super::*;
+
+
+const DAY: u64 = 86_400;
+
+
+fn send(amount: u128) -> CosmosMsg {
+    CosmosMsg::Bank(BankMsg::Send { to_address: "bob".into(), amount: coins(amount, "uatom") })
+}
+
+
+/// Data signed with the given key authorising exactly the given messages
+fn signed_msgs(key: &SigningKey, env: &Env, nonce: u128, msgs: &[CosmosMsg]) -> SignedDataMsg {
+    signed_data(key, to_json_binary(&MsgDataToSign {
+        chain_id: env.block.chain_id.clone(),
+        contract_address: env.contract.address.to_string(),
+        messages: msgs.to_vec(),
+        nonce: nonce.to_string(),
+        account_id: None,
+    }).unwrap())
+}
+
+
+/// Verify the messages signed with the given key and record their spendings
+fn spend(
+    deps: &mut OwnedDeps<MockStorage, MockApi, MockQuerier>, 
+    env: &Env, 
+    key: &SigningKey, 
+    nonce: u128, 
+    msgs: &[CosmosMsg]
+) -> Result<Vec<Event>, AuthError> {
+    verify_cosmos_msgs(&deps.api, &mut deps.storage, env, signed_msgs(key, env, nonce, msgs), msgs, None)
+}
+
+
+/// Account with an unrestricted credential under seed 1 and an executor
+/// under seed 2 that can spend 100 uatom per day
+fn setup() -> OwnedDeps<MockStorage, MockApi, MockQuerier> {
+    let mut deps = mock_dependencies();
+    let env = mock_env();
+    let data = CredentialData {
+        settings: Some(vec![
+            CredentialSettings::default(),
+            CredentialSettings {
+                role: Some(CredentialRole::Executor),
+                spending_limits: Some(vec![SpendingLimit {
+                    denom: "uatom".into(),
+                    amount: Uint128::new(100),
+                    period: DAY,
+                }]),
+                ..Default::default()
+            },
+        ]),
+        ..credential_data((1..=2).map(|i| cosmos_credential(&signing_key(i), data_to_sign(&env, 0))).collect())
+    };
+    data.save(&deps.api, &mut deps.storage, &env, &mock_info("alice", &[]), None).unwrap();
+    deps
+}
+
+
+#[test]
+fn limits_over_rolling_window() {
+    let mut deps = setup();
+    let mut env = mock_env();
+    let limited = signing_key(2);
+
+    spend(&mut deps, &env, &limited, 1, &[send(60)]).unwrap();
+
+    env.block.time = env.block.time.plus_seconds(DAY / 2);
+    let err = spend(&mut deps, &env, &limited, 2, &[send(50)]);
+    assert_eq!(err.unwrap_err(), AuthError::SpendingLimitExceeded("uatom".into()));
+    spend(&mut deps, &env, &limited, 2, &[send(40)]).unwrap();
+
+    // the first spending leaves the window
+    env.block.time = env.block.time.plus_seconds(DAY / 2);
+    spend(&mut deps, &env, &limited, 3, &[send(60)]).unwrap();
+
+    // the unrestricted credential isn't affected
+    spend(&mut deps, &env, &signing_key(1), 4, &[send(1_000)]).unwrap();
+}
+
+
+#[test]
+fn messages_must_match_the_signed_ones() {
+    let mut deps = setup();
+    let env = mock_env();
+    let limited = signing_key(2);
+    let signed = signed_msgs(&limited, &env, 1, &[send(1)]);
+
+    let err = verify_cosmos_msgs(&deps.api, &mut deps.storage, &env, signed.clone(), &[send(1_000)], None);
+    assert_eq!(err.unwrap_err(), AuthError::MessagesMismatch);
+
+    // data that doesn't list the messages can't authorise any
+    let err = verify_cosmos_msgs(&deps.api, &mut deps.storage, &env, signed_msg(&limited, &env, 1), &[send(1)], None);
+    assert!(err.is_err());
+
+    verify_cosmos_msgs(&deps.api, &mut deps.storage, &env, signed, &[send(1)], None).unwrap();
+}
+
+
+#[test]
+fn funds_of_all_messages_are_counted() {
+    let mut deps = setup();
+    let env = mock_env();
+    let limited = signing_key(2);
+
+    let execute = CosmosMsg::Wasm(WasmMsg::Execute {
+        contract_addr: "dex".into(),
+        msg: cosmwasm_std::Binary::from(b"{}"),
+        funds: coins(50, "uatom"),
+    });
+    let err = spend(&mut deps, &env, &limited, 1, &[send(60), execute.clone()]);
+    assert_eq!(err.unwrap_err(), AuthError::SpendingLimitExceeded("uatom".into()));
+
+    // denominations without a limit can't be spent
+    let other = CosmosMsg::<Empty>::Bank(BankMsg::Burn { amount: coins(1, "uosmo") });
+    let err = spend(&mut deps, &env, &limited, 1, &[other]);
+    assert_eq!(err.unwrap_err(), AuthError::SpendingLimitExceeded("uosmo".into()));
+
+    // messages that can't be inspected are rejected
+    let custom = CosmosMsg::Custom(Empty {});
+    assert!(spend(&mut deps, &env, &limited, 1, &[custom]).is_err());
+
+    // as well as the ones that change the admin or the code of other contracts
+    let migrate = CosmosMsg::<Empty>::Wasm(WasmMsg::Migrate {
+        contract_addr: "dex".into(),
+        new_code_id: 2,
+        msg: cosmwasm_std::Binary::from(b"{}"),
+    });
+    let clear = CosmosMsg::Wasm(WasmMsg::ClearAdmin { contract_addr: "dex".into() });
+    for msg in [migrate, clear] {
+        assert!(spend(&mut deps, &env, &limited, 1, &[msg]).is_err());
+    }
+
+    spend(&mut deps, &env, &limited, 1, &[send(50), execute]).unwrap();
+
+    // limited credentials can't skip the inspection
+    let err = verify_actions(&deps.api, &mut deps.storage, &env, signed_msg(&limited, &env, 2), None);
+    assert_eq!(err.unwrap_err(), AuthError::Unauthorized);
+}
+
+
+#[test]
+fn tokens_of_contracts_are_not_limited() {
+    let mut deps = setup();
+    let env = mock_env();
+
+    // the limits only apply to native funds and not to the messages of the executions
+    let transfer = CosmosMsg::Wasm(WasmMsg::Execute {
+        contract_addr: "cw20".into(),
+        msg: cosmwasm_std::Binary::from(br#"{"transfer":{"recipient":"bob","amount":"1000000"}}"#),
+        funds: vec![],
+    });
+    spend(&mut deps, &env, &signing_key(2), 1, &[transfer]).unwrap();
+}
+
+
+#[test]
+fn limits_are_removed_with_credential() {
+    let mut deps = setup();
+    let env = mock_env();
+    let info = mock_info("alice", &[]);
+    let limited = cosmos_credential(&signing_key(2), data_to_sign(&env, 1));
+
+    let signer = credential_data(vec![cosmos_credential(&signing_key(1), data_to_sign(&env, 1))]);
+    signer.update(
+        UpdateOperation::Remove(credential_data(vec![limited.clone()])), &deps.api, &mut deps.storage, &env, &info, None
+    ).unwrap();
+    assert_eq!(saa_common::spending::get_spending_limits(&deps.storage, &limited.id()).unwrap(), None);
+
+    // added again without limits
+    let signer = credential_data(vec![cosmos_credential(&signing_key(1), data_to_sign(&env, 2))]);
+    signer.update(
+        UpdateOperation::Add(credential_data(vec![cosmos_credential(&signing_key(2), data_to_sign(&env, 2))])),
+        &deps.api, &mut deps.storage, &env, &info, None
+    ).unwrap();
+    verify_actions(&deps.api, &mut deps.storage, &env, signed_msg(&signing_key(2), &env, 3), None).unwrap();
+}
+
+
+#[test]
+fn overflowing_amounts_exceed_the_limit() {
+    let mut deps = setup();
+    let env = mock_env();
+    let err = spend(&mut deps, &env, &signing_key(2), 1, &[send(u128::MAX), send(1)]);
+    assert_eq!(err.unwrap_err(), AuthError::SpendingLimitExceeded("uatom".into()));
+}
+
+
+#[test]
+fn admins_can_not_have_limits() {
+    let env = mock_env();
+    let data = CredentialData {
+        settings: Some(vec![CredentialSettings {
+            spending_limits: Some(vec![SpendingLimit { denom: "uatom".into(), amount: Uint128::new(100), period: DAY }]),
+            ..Default::default()
+        }]),
+        ..credential_data(vec![cosmos_credential(&signing_key(1), data_to_sign(&env, 0))])
+    };
+    assert_eq!(
+        data.validate().unwrap_err(),
+        AuthError::InvalidData("Admin credentials can't have spending limits".to_string())
+    );
+}
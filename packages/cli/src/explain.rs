@@ -0,0 +1,220 @@
+use core::fmt;
+
+use saa_auth::passkey::{utils::rp_id_matches, PasskeyCredential};
+use smart_account_auth::{
+    cosmos_utils::sign_doc_arb_036,
+    crypto,
+    eth_utils::{get_recovery_param, preamble_msg_eth},
+    from_json, to_json_binary,
+    hashes::{keccak256, sha256},
+    messages::MsgDataToVerify,
+    utils::pubkey_to_address,
+    AuthError, CosmosArbitrary, Credential, EthPersonalSign, Siwe, Verifiable,
+};
+
+
+/// Values that the signed message is expected to contain
+#[derive(Clone, Debug, Default)]
+pub struct Context {
+    pub chain_id: Option<String>,
+    pub contract: Option<String>,
+    pub nonce: Option<String>,
+    /// origin of the passkeys
+    pub origin: Option<String>,
+}
+
+
+impl Context {
+    fn is_empty(&self) -> bool {
+        self.chain_id.is_none() && self.contract.is_none() && self.nonce.is_none()
+    }
+}
+
+
+/// Outcome of a single step of the verification
+#[derive(Clone, Debug, PartialEq)]
+pub struct Check {
+    pub name: &'static str,
+    pub passed: bool,
+    pub detail: String,
+}
+
+
+impl Check {
+    fn pass(name: &'static str, detail: impl Into<String>) -> Self {
+        Self { name, passed: true, detail: detail.into() }
+    }
+
+    fn fail(name: &'static str, detail: impl Into<String>) -> Self {
+        Self { name, passed: false, detail: detail.into() }
+    }
+
+    fn result(name: &'static str, res: Result<(), AuthError>, detail: impl Into<String>) -> Self {
+        match res {
+            Ok(()) => Self::pass(name, detail),
+            Err(e) => Self::fail(name, format!("{}; {}", e, detail.into())),
+        }
+    }
+}
+
+
+impl fmt::Display for Check {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let status = if self.passed { "ok" } else { "FAILED" };
+        write!(f, "[{status}] {}: {}", self.name, self.detail)
+    }
+}
+
+
+/// Run every check of the credential one by one instead of stopping at the first failure
+pub fn explain(credential: &Credential, ctx: &Context) -> Vec<Check> {
+    let mut checks = vec![Check::result(
+        "structure",
+        credential.validate(),
+        credential.name().as_str(),
+    )];
+    let data = match credential {
+        Credential::Siwe(c) => c.replay_data(),
+        _ => from_json(credential.message()),
+    };
+    checks.extend(context_checks(data, ctx));
+    match credential {
+        Credential::EthPersonalSign(c) => checks.extend(eth_checks(c)),
+        Credential::Siwe(c) => checks.extend(siwe_checks(c)),
+        Credential::CosmosArbitrary(c) => checks.extend(cosmos_checks(c)),
+        Credential::Passkey(c) => checks.extend(passkey_checks(c, ctx)),
+        Credential::Secp256k1(_) | Credential::Secp256r1(_) | Credential::Ed25519(_) => {
+            checks.push(Check::pass("digest", hex::encode(sha256(&credential.message()))));
+        }
+        Credential::Caller(_) => {
+            checks.push(Check::fail("signature", "caller credentials can only be checked on chain"));
+            return checks;
+        }
+        Credential::Custom(c) => {
+            checks.push(Check::fail("signature", format!("verifier '{}' is passed by the contract", c.name)));
+            return checks;
+        }
+    }
+    checks.push(Check::result("signature", credential.verify(), "verified natively"));
+    checks
+}
+
+
+fn context_checks(data: Result<MsgDataToVerify, AuthError>, ctx: &Context) -> Vec<Check> {
+    if ctx.is_empty() {
+        return vec![];
+    }
+    let data = match data {
+        Ok(data) => data,
+        Err(e) => return vec![Check::fail("message", format!("not in the format of MsgDataToSign: {e}"))],
+    };
+    [
+        ("chain id", &ctx.chain_id, &data.chain_id),
+        ("contract", &ctx.contract, &data.contract_address),
+        ("nonce", &ctx.nonce, &data.nonce),
+    ]
+    .into_iter()
+    .filter_map(|(name, expected, signed)| expected.as_ref().map(|expected| {
+        if expected == signed {
+            Check::pass(name, signed.clone())
+        } else {
+            Check::fail(name, format!("signed for '{signed}' but expected '{expected}'"))
+        }
+    }))
+    .collect()
+}
+
+
+fn siwe_checks(c: &Siwe) -> Vec<Check> {
+    match c.parsed() {
+        Ok(parsed) => {
+            let mut checks = vec![Check::pass(
+                "siwe", 
+                format!("signed for {} at {} on chain {}", parsed.domain, parsed.uri, parsed.chain_id)
+            )];
+            checks.extend(eth_checks(&EthPersonalSign {
+                message: c.message.as_bytes().into(),
+                signature: c.signature.clone(),
+                signer: parsed.address,
+            }));
+            checks
+        },
+        Err(e) => vec![Check::fail("siwe", e.to_string())],
+    }
+}
+
+
+fn eth_checks(c: &EthPersonalSign) -> Vec<Check> {
+    let digest = preamble_msg_eth(&c.message);
+    let mut checks = vec![Check::pass("digest", hex::encode(digest))];
+    if c.signature.len() < 65 {
+        return checks;
+    }
+    let recovered = get_recovery_param(c.signature[64]).and_then(|param| {
+        Ok(crypto::secp256k1_recover_pubkey(&digest, &c.signature[..64], param)?)
+    });
+    checks.push(match recovered {
+        Ok(key) => {
+            let address = format!("0x{}", hex::encode(&keccak256(&key[1..])[12..]));
+            if address.eq_ignore_ascii_case(&c.signer) {
+                Check::pass("recovered address", address)
+            } else {
+                Check::fail("recovered address", format!("{address} doesn't match the signer {}", c.signer))
+            }
+        }
+        Err(e) => Check::fail("recovered address", e.to_string()),
+    });
+    checks
+}
+
+
+fn cosmos_checks(c: &CosmosArbitrary) -> Vec<Check> {
+    let Some(hrp) = c.hrp.as_ref() else {
+        return vec![Check::fail("address", "prefix of the address is missing")];
+    };
+    match pubkey_to_address(&c.pubkey, hrp) {
+        Ok(address) => {
+            let preamble = sign_doc_arb_036(&address, &c.message);
+            vec![
+                Check::pass("address", address),
+                Check::pass("digest", hex::encode(sha256(preamble.as_bytes()))),
+            ]
+        }
+        Err(e) => vec![Check::fail("address", e.to_string())],
+    }
+}
+
+
+fn passkey_checks(c: &PasskeyCredential, ctx: &Context) -> Vec<Check> {
+    let client_data = &c.client_data;
+    let mut checks = vec![];
+
+    if let Some(origin) = ctx.origin.as_ref() {
+        checks.push(if *origin == client_data.origin {
+            Check::pass("origin", origin.clone())
+        } else {
+            Check::fail("origin", format!("signed at '{}' but expected '{origin}'", client_data.origin))
+        });
+    }
+    if client_data.cross_origin {
+        checks.push(Check::pass("cross origin", "the assertion was made in a cross-origin iframe"));
+    }
+
+    if c.authenticator_data.len() >= 32 {
+        checks.push(if rp_id_matches(&client_data.origin, &c.authenticator_data[..32]) {
+            Check::pass("rp id", client_data.origin.clone())
+        } else {
+            Check::fail("rp id", format!("authenticator data wasn't made for '{}'", client_data.origin))
+        });
+    }
+
+    match to_json_binary(client_data) {
+        Ok(json) => {
+            let mut signed = c.authenticator_data.to_vec();
+            signed.extend(sha256(&json));
+            checks.push(Check::pass("digest", hex::encode(sha256(&signed))));
+        }
+        Err(e) => checks.push(Check::fail("client data", e.to_string())),
+    }
+    checks
+}
@@ -0,0 +1,130 @@
+use std::{fs, path::Path};
+
+use saa_auth::passkey::{ClientData, PasskeyCredential, PasskeyPayload};
+use smart_account_auth::{
+    from_json, messages::{AuthPayload, SignedDataMsg}, Binary, CosmosArbitrary, Credential, CredentialName,
+    Ed25519, EthPersonalSign, Secp256k1, Secp256r1, Siwe,
+};
+
+
+/// Details of a stored credential that aren't a part of signed data
+#[derive(Clone, Debug, Default)]
+pub struct StoredDetails {
+    pub kind: Option<CredentialName>,
+    /// public key in base64
+    pub pubkey: Option<String>,
+    /// address of an Ethereum signer
+    pub signer: Option<String>,
+    pub origin: Option<String>,
+    pub cross_origin: bool,
+}
+
+
+pub fn read_file(path: &Path) -> Result<String, String> {
+    fs::read_to_string(path).map_err(|e| format!("can't read {}: {e}", path.display()))
+}
+
+
+/// Private key stored as hex with an optional `0x` prefix
+pub fn read_key(path: &Path) -> Result<Vec<u8>, String> {
+    let content = read_file(path)?;
+    let content = content.trim();
+    hex::decode(content.strip_prefix("0x").unwrap_or(content))
+        .map_err(|e| format!("key must be hex encoded: {e}"))
+}
+
+
+/// Credential serialized as JSON or `SignedDataMsg` combined with the stored details
+pub fn load_credential(json: &str, stored: &StoredDetails) -> Result<Credential, String> {
+    if let Ok(credential) = from_json::<Credential>(json.as_bytes()) {
+        return Ok(credential);
+    }
+    let signed : SignedDataMsg = from_json(json.as_bytes())
+        .map_err(|e| format!("file contains neither a credential nor signed data: {e}"))?;
+    from_signed_data(signed, stored)
+}
+
+
+fn from_signed_data(signed: SignedDataMsg, stored: &StoredDetails) -> Result<Credential, String> {
+    let kind = stored.kind.clone()
+        .ok_or("--kind is required to verify signed data")?;
+    let payload = signed.payload.unwrap_or(AuthPayload {
+        credential_id: None,
+        hrp: None,
+        address: None,
+        extension: None,
+    });
+    let pubkey = match stored.pubkey.as_ref() {
+        Some(key) => Some(Binary::from_base64(key).map_err(|e| e.to_string())?),
+        None => payload.credential_id.clone().map(Binary::from),
+    };
+    let pubkey = || pubkey.clone().ok_or("--pubkey is required when the payload has no credential id");
+    let SignedDataMsg { data: message, signature, .. } = signed;
+
+    let credential = match kind {
+        CredentialName::EthPersonalSign => Credential::EthPersonalSign(EthPersonalSign {
+            signer: match stored.signer.clone() {
+                Some(signer) => signer,
+                None => String::from_utf8(payload.credential_id.ok_or("--signer is required")?)
+                    .map_err(|e| e.to_string())?,
+            },
+            message,
+            signature,
+        }),
+        CredentialName::CosmosArbitrary => Credential::CosmosArbitrary(CosmosArbitrary {
+            pubkey: pubkey()?,
+            hrp: payload.hrp,
+            message,
+            signature,
+        }),
+        CredentialName::Passkey => {
+            let extension : PasskeyPayload = from_json(
+                payload.extension.ok_or("payload must contain the passkey extension")?
+            ).map_err(|e| e.to_string())?;
+            let origin = stored.origin.clone().ok_or("--origin is required for passkeys")?;
+            let pubkey = match extension.pubkey {
+                Some(key) => key,
+                None => Binary::from_base64(stored.pubkey.as_ref().ok_or("--pubkey is required")?)
+                    .map_err(|e| e.to_string())?,
+            };
+            Credential::Passkey(PasskeyCredential {
+                id: String::from_utf8(payload.credential_id.unwrap_or_default())
+                    .map_err(|e| e.to_string())?,
+                client_data: ClientData::new(
+                    "webauthn.get".into(),
+                    message.to_base64url(),
+                    origin,
+                    stored.cross_origin,
+                    extension.other_keys.unwrap_or_default(),
+                ),
+                authenticator_data: extension.authenticator_data,
+                pubkey: Some(pubkey),
+                user_handle: None,
+                signature,
+            })
+        }
+        CredentialName::Secp256k1 => Credential::Secp256k1(Secp256k1 {
+            pubkey: pubkey()?,
+            hrp: payload.hrp,
+            message,
+            signature,
+        }),
+        CredentialName::Secp256r1 => Credential::Secp256r1(Secp256r1 {
+            pubkey: pubkey()?,
+            message,
+            signature,
+        }),
+        CredentialName::Ed25519 => Credential::Ed25519(Ed25519 {
+            pubkey: pubkey()?,
+            message,
+            signature,
+        }),
+        CredentialName::Caller => return Err("caller credentials can't be signed".into()),
+        CredentialName::Siwe => Credential::Siwe(Siwe {
+            message: String::from_utf8(message.to_vec()).map_err(|e| e.to_string())?,
+            signature,
+        }),
+        CredentialName::Custom => return Err("custom credentials are verified by the contracts that define them".into()),
+    };
+    Ok(credential)
+}
@@ -0,0 +1,189 @@
+//! `saa` - offline tool for signing messages in the format of smart account auth
+//! and for debugging credentials that fail verification
+mod explain;
+mod input;
+mod sign;
+
+#[cfg(test)]
+mod tests;
+
+use std::{path::PathBuf, process::ExitCode};
+
+use clap::{Args, Parser, Subcommand, ValueEnum};
+use smart_account_auth::{client, CredentialName};
+
+use explain::{explain, Context};
+use input::StoredDetails;
+use sign::SignOptions;
+
+
+#[derive(Parser)]
+#[command(name = "saa", version, about = "Sign and verify smart account auth credentials offline")]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+}
+
+
+#[derive(Subcommand)]
+enum Command {
+    /// Verify a `Credential` or a `SignedDataMsg` JSON file and explain every check
+    Verify {
+        /// path to the JSON file
+        file: PathBuf,
+        #[command(flatten)]
+        context: ContextArgs,
+        #[command(flatten)]
+        stored: StoredArgs,
+    },
+    /// Sign a `MsgDataToSign` payload with a local private key
+    Sign {
+        #[arg(long, value_enum)]
+        kind: Kind,
+        /// path to a file with a hex encoded private key
+        #[arg(long)]
+        key: PathBuf,
+        #[arg(long)]
+        chain_id: String,
+        #[arg(long)]
+        contract: String,
+        #[arg(long)]
+        nonce: u128,
+        /// path to a JSON array of the messages to sign. Empty by default
+        #[arg(long)]
+        messages: Option<PathBuf>,
+        /// prefix of the cosmos addresses
+        #[arg(long, default_value = "cosmos")]
+        hrp: String,
+        /// origin of the passkeys
+        #[arg(long, default_value = "http://localhost")]
+        origin: String,
+        /// id of the passkeys
+        #[arg(long, default_value = "saa-cli")]
+        id: String,
+        /// output `SignedDataMsg` instead of the credential
+        #[arg(long)]
+        signed_data: bool,
+    },
+}
+
+
+/// Values that the signed message is expected to contain
+#[derive(Args)]
+struct ContextArgs {
+    #[arg(long)]
+    chain_id: Option<String>,
+    #[arg(long)]
+    contract: Option<String>,
+    #[arg(long)]
+    nonce: Option<u128>,
+    /// expected origin of passkeys
+    #[arg(long)]
+    origin: Option<String>,
+}
+
+
+/// Details of the stored credential needed to verify a `SignedDataMsg`
+#[derive(Args)]
+struct StoredArgs {
+    #[arg(long, value_enum)]
+    kind: Option<Kind>,
+    /// base64 encoded public key if it isn't the credential id of the payload
+    #[arg(long)]
+    pubkey: Option<String>,
+    /// address of the Ethereum signer if it isn't the credential id of the payload
+    #[arg(long)]
+    signer: Option<String>,
+    /// the passkey is allowed to be used in cross-origin iframes
+    #[arg(long)]
+    cross_origin: bool,
+}
+
+
+#[derive(Clone, Copy, Debug, PartialEq, ValueEnum)]
+enum Kind {
+    Eth,
+    Cosmos,
+    Passkey,
+    Secp256k1,
+    Secp256r1,
+    Ed25519,
+    Siwe,
+}
+
+
+impl From<Kind> for CredentialName {
+    fn from(kind: Kind) -> Self {
+        match kind {
+            Kind::Eth => CredentialName::EthPersonalSign,
+            Kind::Cosmos => CredentialName::CosmosArbitrary,
+            Kind::Passkey => CredentialName::Passkey,
+            Kind::Secp256k1 => CredentialName::Secp256k1,
+            Kind::Secp256r1 => CredentialName::Secp256r1,
+            Kind::Ed25519 => CredentialName::Ed25519,
+            Kind::Siwe => CredentialName::Siwe,
+        }
+    }
+}
+
+
+fn main() -> ExitCode {
+    match run(Cli::parse().command) {
+        Ok(true) => ExitCode::SUCCESS,
+        Ok(false) => ExitCode::from(1),
+        Err(e) => {
+            eprintln!("error: {e}");
+            ExitCode::from(2)
+        }
+    }
+}
+
+
+/// Execute the command and return whether it succeeded
+fn run(command: Command) -> Result<bool, String> {
+    match command {
+        Command::Verify { file, context, stored } => {
+            let stored = StoredDetails {
+                kind: stored.kind.map(Into::into),
+                pubkey: stored.pubkey,
+                signer: stored.signer,
+                origin: context.origin.clone(),
+                cross_origin: stored.cross_origin,
+            };
+            let credential = input::load_credential(&input::read_file(&file)?, &stored)?;
+            let checks = explain(&credential, &Context {
+                chain_id: context.chain_id,
+                contract: context.contract,
+                nonce: context.nonce.map(|n| n.to_string()),
+                origin: context.origin,
+            });
+            for check in checks.iter() {
+                println!("{check}");
+            }
+            Ok(checks.iter().all(|c| c.passed))
+        }
+        Command::Sign { kind, key, chain_id, contract, nonce, messages, hrp, origin, id, signed_data } => {
+            let messages : Vec<serde_json::Value> = match messages {
+                Some(path) => serde_json::from_str(&input::read_file(&path)?)
+                    .map_err(|e| format!("messages must be a JSON array: {e}"))?,
+                None => vec![],
+            };
+            let message = client::data_to_sign(&chain_id, &contract, messages, nonce)
+                .map_err(|e| e.to_string())?;
+            let credential = sign::sign(
+                &kind.into(),
+                &input::read_key(&key)?,
+                message,
+                &SignOptions { hrp, origin, id },
+            ).map_err(|e| e.to_string())?;
+
+            let output = if signed_data {
+                serde_json::to_string_pretty(&client::signed_data_msg(&credential).map_err(|e| e.to_string())?)
+            } else {
+                serde_json::to_string_pretty(&credential)
+            };
+            println!("{}", output.map_err(|e| e.to_string())?);
+            Ok(true)
+        }
+    }
+}
@@ -0,0 +1,45 @@
+use smart_account_auth::{
+    client::{Ed25519Key, Secp256k1Key, Secp256r1Key},
+    AuthError, Binary, Credential, CredentialName,
+};
+
+
+/// Parameters of the credentials that aren't derived from the key
+#[derive(Clone, Debug)]
+pub struct SignOptions {
+    /// prefix of the cosmos addresses
+    pub hrp: String,
+    /// origin of the passkeys
+    pub origin: String,
+    /// id of the passkeys
+    pub id: String,
+}
+
+
+/// Credential of the given kind with the message signed by the private key
+pub fn sign(
+    kind: &CredentialName,
+    secret: &[u8],
+    message: Binary,
+    options: &SignOptions,
+) -> Result<Credential, AuthError> {
+    match kind {
+        CredentialName::EthPersonalSign => Secp256k1Key::from_bytes(secret)?
+            .eth_personal_sign(message),
+        CredentialName::CosmosArbitrary => Secp256k1Key::from_bytes(secret)?
+            .cosmos_arbitrary(message, &options.hrp),
+        CredentialName::Secp256k1 => Secp256k1Key::from_bytes(secret)?
+            .secp256k1(message, Some(options.hrp.clone())),
+        CredentialName::Passkey => Secp256r1Key::from_bytes(secret)?
+            .passkey(message, &options.id, &options.origin),
+        CredentialName::Secp256r1 => Secp256r1Key::from_bytes(secret)?
+            .secp256r1(message),
+        CredentialName::Ed25519 => Ed25519Key::from_bytes(secret)?
+            .ed25519(message),
+        CredentialName::Siwe => Secp256k1Key::from_bytes(secret)?
+            .siwe(&String::from_utf8(message.to_vec())?),
+        CredentialName::Caller | CredentialName::Custom => Err(
+            AuthError::UnsupportedCredential(kind.as_str().to_string())
+        ),
+    }
+}
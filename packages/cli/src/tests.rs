@@ -0,0 +1,130 @@
+use smart_account_auth::{client, to_json_binary, Credential, CredentialName};
+
+use crate::{
+    explain::{explain, Check, Context},
+    input::{load_credential, StoredDetails},
+    sign::{sign, SignOptions},
+};
+
+
+const KINDS : [CredentialName; 6] = [
+    CredentialName::EthPersonalSign,
+    CredentialName::CosmosArbitrary,
+    CredentialName::Passkey,
+    CredentialName::Secp256k1,
+    CredentialName::Secp256r1,
+    CredentialName::Ed25519,
+];
+
+
+fn options() -> SignOptions {
+    SignOptions {
+        hrp: "neutron".into(),
+        origin: "https://app.example.com".into(),
+        id: "passkey".into(),
+    }
+}
+
+
+fn context(nonce: u128) -> Context {
+    Context {
+        chain_id: Some("pion-1".into()),
+        contract: Some("neutron1contract".into()),
+        nonce: Some(nonce.to_string()),
+        origin: Some(options().origin),
+    }
+}
+
+
+fn signed(kind: &CredentialName) -> Credential {
+    let message = client::data_to_sign("pion-1", "neutron1contract", vec!["test"], 5).unwrap();
+    sign(kind, &[7; 32], message, &options()).unwrap()
+}
+
+
+fn failed(checks: &[Check]) -> Vec<&'static str> {
+    checks.iter().filter(|c| !c.passed).map(|c| c.name).collect()
+}
+
+
+#[test]
+fn signed_credentials_pass_every_check() {
+    for kind in KINDS.iter() {
+        let checks = explain(&signed(kind), &context(5));
+        assert!(failed(&checks).is_empty(), "{kind:?}: {checks:?}");
+        assert!(checks.iter().any(|c| c.name == "signature"));
+    }
+}
+
+
+#[test]
+fn context_mismatches_are_explained() {
+    for kind in KINDS.iter() {
+        let checks = explain(&signed(kind), &Context {
+            chain_id: Some("neutron-1".into()),
+            ..context(6)
+        });
+        assert_eq!(failed(&checks), vec!["chain id", "nonce"], "{kind:?}");
+        let nonce = checks.iter().find(|c| c.name == "nonce").unwrap();
+        assert_eq!(nonce.detail, "signed for '5' but expected '6'");
+    }
+}
+
+
+#[test]
+fn wrong_eth_signer_is_explained() {
+    let Credential::EthPersonalSign(mut eth) = signed(&CredentialName::EthPersonalSign) else {
+        unreachable!()
+    };
+    let signer = eth.signer.clone();
+    eth.signer = format!("0x{}", "ab".repeat(20));
+
+    let checks = explain(&Credential::EthPersonalSign(eth), &context(5));
+    assert_eq!(failed(&checks), vec!["recovered address", "signature"]);
+    let recovered = checks.iter().find(|c| c.name == "recovered address").unwrap();
+    assert!(recovered.detail.starts_with(&signer));
+}
+
+
+#[test]
+fn passkey_origin_is_explained() {
+    let Credential::Passkey(mut passkey) = signed(&CredentialName::Passkey) else {
+        unreachable!()
+    };
+    let checks = explain(&Credential::Passkey(passkey.clone()), &Context {
+        origin: Some("https://evil.com".into()),
+        ..context(5)
+    });
+    assert_eq!(failed(&checks), vec!["origin"]);
+
+    passkey.client_data.origin = "https://evil.com".into();
+    let checks = explain(&Credential::Passkey(passkey), &Context {
+        origin: Some("https://evil.com".into()),
+        ..context(5)
+    });
+    assert_eq!(failed(&checks), vec!["structure", "rp id", "signature"]);
+}
+
+
+#[test]
+fn signed_data_is_verified_with_stored_details() {
+    for kind in KINDS.iter() {
+        let signed = client::signed_data_msg(&signed(kind)).unwrap();
+        let json = String::from_utf8(to_json_binary(&signed).unwrap().to_vec()).unwrap();
+
+        let stored = StoredDetails {
+            kind: Some(kind.clone()),
+            origin: Some(options().origin),
+            ..Default::default()
+        };
+        let credential = load_credential(&json, &stored).unwrap();
+        assert_eq!(credential.name(), *kind);
+        let checks = explain(&credential, &context(5));
+        assert!(failed(&checks).is_empty(), "{kind:?}: {checks:?}");
+    }
+
+    let signed = client::signed_data_msg(&signed(&CredentialName::Ed25519)).unwrap();
+    let json = String::from_utf8(to_json_binary(&signed).unwrap().to_vec()).unwrap();
+    assert!(load_credential(&json, &StoredDetails::default()).is_err());
+    assert!(load_credential("{}", &StoredDetails::default()).is_err());
+}
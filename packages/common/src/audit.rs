@@ -0,0 +1,77 @@
+use saa_schema::wasm_serde;
+
+use crate::{cosmwasm::{Env, Storage}, hashes::sha256, AuthError, Binary, BlockStamp, CredentialId, String, ToString, Vec};
+use crate::storage::{AUDIT_COUNT, AUDIT_LOG, ACCOUNT_NUMBER, DEFAULT_LIMIT, MAX_LIMIT};
+
+
+/// Number of the latest actions kept in the log. Older records get overwritten
+pub const AUDIT_CAPACITY: u64 = 100;
+
+
+#[wasm_serde]
+pub struct AuditRecord {
+    /// sequence number of the record starting from 0
+    pub sequence: u64,
+    /// id of the credential that authorised the action
+    pub credential_id: Binary,
+    /// account number used as a nonce of the signed data
+    pub nonce: String,
+    /// block at which the action has been authorised
+    pub block: BlockStamp,
+    /// sha256 hash of the signed data
+    pub data_hash: Binary,
+}
+
+
+/// Record an action authorised by the given credential. Must be called before the account number is incremented
+pub fn record_action(
+    storage: &mut dyn Storage,
+    env: &Env,
+    id: &CredentialId,
+    signed_data: &Binary,
+) -> Result<(), AuthError> {
+    let sequence = AUDIT_COUNT.may_load(storage)?.unwrap_or_default();
+    let record = AuditRecord {
+        sequence,
+        credential_id: Binary::new(id.clone()),
+        nonce: ACCOUNT_NUMBER.may_load(storage)?.unwrap_or_default().to_string(),
+        block: env.into(),
+        data_hash: Binary::new(sha256(signed_data.as_slice())),
+    };
+    #[cfg(feature = "cosmwasm")]
+    AUDIT_LOG.save(storage, sequence % AUDIT_CAPACITY, &record)?;
+    #[cfg(all(feature = "secretwasm", not(feature = "cosmwasm")))]
+    AUDIT_LOG.insert(storage, &(sequence % AUDIT_CAPACITY), &record)?;
+    AUDIT_COUNT.save(storage, &(sequence + 1))?;
+    Ok(())
+}
+
+
+/// Total number of the recorded actions including the ones that have been overwritten
+pub fn audit_count(storage: &dyn Storage) -> Result<u64, AuthError> {
+    Ok(AUDIT_COUNT.may_load(storage)?.unwrap_or_default())
+}
+
+
+/// Records that are still kept in the log in the order of their sequence numbers
+pub fn get_audit_log(
+    storage: &dyn Storage,
+    start_after: Option<u64>,
+    limit: Option<u32>,
+) -> Result<Vec<AuditRecord>, AuthError> {
+    let limit = limit.unwrap_or(DEFAULT_LIMIT).min(MAX_LIMIT) as u64;
+    let count = audit_count(storage)?;
+    let oldest = count.saturating_sub(AUDIT_CAPACITY);
+    let start = start_after.map(|s| s.saturating_add(1)).unwrap_or_default().max(oldest);
+    let end = start.saturating_add(limit).min(count);
+
+    (start..end).map(|sequence| {
+        #[cfg(feature = "cosmwasm")]
+        let record = AUDIT_LOG.load(storage, sequence % AUDIT_CAPACITY)?;
+        #[cfg(all(feature = "secretwasm", not(feature = "cosmwasm")))]
+        let record = AUDIT_LOG.get(storage, &(sequence % AUDIT_CAPACITY))
+            .ok_or(AuthError::NotFound)?;
+        Ok(record)
+    })
+    .collect()
+}
@@ -47,7 +47,7 @@ impl Binary {
         Self::B64_ENGINE
             .decode(encoded.as_bytes())
             .map(Binary::from)
-            .map_err(|_| AuthError::generic("invalid base64"))
+            .map_err(|_| AuthError::InvalidEncoding("invalid base64".to_string()))
     }
 
     /// encode to base64 string (guaranteed to be success as we control the data inside).
@@ -56,6 +56,53 @@ impl Binary {
         Self::B64_ENGINE.encode(self.0.as_slice())
     }
 
+    /// URL safe base64 engine used by WebAuthn for challenges and credential ids.
+    ///
+    /// The engine omits padding when encoding and accepts strings with or
+    /// without padding when decoding.
+    const B64_URL_ENGINE: GeneralPurpose = GeneralPurpose::new(
+        &base64::alphabet::URL_SAFE,
+        base64::engine::GeneralPurposeConfig::new()
+            .with_encode_padding(false)
+            .with_decode_padding_mode(base64::engine::DecodePaddingMode::Indifferent),
+    );
+
+    /// take an (untrusted) base64url string and decode it into bytes.
+    /// fails if it is not valid base64url
+    pub fn from_base64url(encoded: &str) -> Result<Self, AuthError> {
+        Self::B64_URL_ENGINE
+            .decode(encoded.as_bytes())
+            .map(Binary::from)
+            .map_err(|_| AuthError::InvalidEncoding("invalid base64url".to_string()))
+    }
+
+    /// encode to base64url string without padding
+    pub fn to_base64url(&self) -> String {
+        Self::B64_URL_ENGINE.encode(self.0.as_slice())
+    }
+
+    /// take an (untrusted) hex string with or without `0x` prefix and decode it into bytes.
+    /// fails if it is not valid hex
+    pub fn from_hex(encoded: &str) -> Result<Self, AuthError> {
+        let encoded = encoded
+            .strip_prefix("0x")
+            .or(encoded.strip_prefix("0X"))
+            .unwrap_or(encoded);
+        hex::decode(encoded)
+            .map(Binary::from)
+            .map_err(|_| AuthError::InvalidEncoding("invalid hex".to_string()))
+    }
+
+    /// encode to lowercase hex string without prefix
+    pub fn to_hex(&self) -> String {
+        hex::encode(self.0.as_slice())
+    }
+
+    /// encode to lowercase hex string with `0x` prefix e.g. for Ethereum signatures
+    pub fn to_prefixed_hex(&self) -> String {
+        format!("0x{}", self.to_hex())
+    }
+
     pub fn as_slice(&self) -> &[u8] {
         self.0.as_slice()
     }
@@ -223,7 +270,7 @@ impl<const LENGTH: usize> PartialEq<Binary> for [u8; LENGTH] {
     }
 }
 
-/// Serializes as a base64 string
+/// Serializes as a base64 string in human readable formats and as raw bytes in the binary ones
 impl Serialize for Binary {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
@@ -232,12 +279,12 @@ impl Serialize for Binary {
         if serializer.is_human_readable() {
             serializer.serialize_str(&self.to_base64())
         } else {
-            panic!("Binary is only intended to be used with JSON serialization for now. If you are hitting this panic please open an issue at https://github.com/CosmWasm/cosmwasm describing your use case.")
+            serializer.serialize_bytes(&self.0)
         }
     }
 }
 
-/// Deserializes as a base64 string
+/// Deserializes from a base64 string in human readable formats and from raw bytes in the binary ones
 impl<'de> Deserialize<'de> for Binary {
     fn deserialize<D>(deserializer: D) -> Result<Binary, D::Error>
     where
@@ -246,7 +293,7 @@ impl<'de> Deserialize<'de> for Binary {
         if deserializer.is_human_readable() {
             deserializer.deserialize_str(Base64Visitor)
         } else {
-            panic!("Binary is only intended to be used with JSON serialization for now. If you are hitting this panic please open an issue at https://github.com/CosmWasm/cosmwasm describing your use case.")
+            deserializer.deserialize_byte_buf(BytesVisitor)
         }
     }
 }
@@ -273,6 +320,201 @@ impl<'de> de::Visitor<'de> for Base64Visitor {
     }
 }
 
+
+struct BytesVisitor;
+
+impl<'de> de::Visitor<'de> for BytesVisitor {
+    type Value = Binary;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("byte array")
+    }
+
+    fn visit_bytes<E>(self, v: &[u8]) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        Ok(Binary::from(v))
+    }
+
+    fn visit_byte_buf<E>(self, v: Vec<u8>) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        Ok(Binary(v))
+    }
+
+    // formats without a dedicated type for bytes encode them as a sequence of integers
+    fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+    where
+        A: de::SeqAccess<'de>,
+    {
+        let mut bytes = Vec::with_capacity(seq.size_hint().unwrap_or_default());
+        while let Some(byte) = seq.next_element::<u8>()? {
+            bytes.push(byte);
+        }
+        Ok(Binary(bytes))
+    }
+}
+
+/// Alternative string encodings of `Binary` fields in human readable formats
+#[derive(Clone, Copy)]
+enum Encoding {
+    Hex,
+    Base64Url,
+}
+
+impl Encoding {
+    fn encode(self, binary: &Binary) -> String {
+        match self {
+            Encoding::Hex => binary.to_hex(),
+            Encoding::Base64Url => binary.to_base64url(),
+        }
+    }
+
+    fn decode(self, encoded: &str) -> Result<Binary, AuthError> {
+        match self {
+            Encoding::Hex => Binary::from_hex(encoded),
+            Encoding::Base64Url => Binary::from_base64url(encoded),
+        }
+    }
+
+    fn serialize<S: ser::Serializer>(self, binary: &Binary, serializer: S) -> Result<S::Ok, S::Error> {
+        if serializer.is_human_readable() {
+            serializer.serialize_str(&self.encode(binary))
+        } else {
+            binary.serialize(serializer)
+        }
+    }
+
+    fn deserialize<'de, D: Deserializer<'de>>(self, deserializer: D) -> Result<Binary, D::Error> {
+        if deserializer.is_human_readable() {
+            deserializer.deserialize_str(EncodedVisitor(self))
+        } else {
+            Binary::deserialize(deserializer)
+        }
+    }
+
+    fn serialize_option<S: ser::Serializer>(self, binary: &Option<Binary>, serializer: S) -> Result<S::Ok, S::Error> {
+        match binary {
+            Some(binary) => serializer.serialize_some(&Encoded(self, binary)),
+            None => serializer.serialize_none(),
+        }
+    }
+
+    fn deserialize_option<'de, D: Deserializer<'de>>(self, deserializer: D) -> Result<Option<Binary>, D::Error> {
+        deserializer.deserialize_option(EncodedOptionVisitor(self))
+    }
+}
+
+
+struct Encoded<'a>(Encoding, &'a Binary);
+
+impl Serialize for Encoded<'_> {
+    fn serialize<S: ser::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.0.serialize(self.1, serializer)
+    }
+}
+
+
+struct EncodedVisitor(Encoding);
+
+impl<'de> de::Visitor<'de> for EncodedVisitor {
+    type Value = Binary;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        match self.0 {
+            Encoding::Hex => formatter.write_str("valid hex encoded string"),
+            Encoding::Base64Url => formatter.write_str("valid base64url encoded string"),
+        }
+    }
+
+    fn visit_str<E>(self, v: &str) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        self.0.decode(v).map_err(|e| E::custom(format!("{e}: {v}")))
+    }
+}
+
+
+struct EncodedOptionVisitor(Encoding);
+
+impl<'de> de::Visitor<'de> for EncodedOptionVisitor {
+    type Value = Option<Binary>;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("optional encoded string")
+    }
+
+    fn visit_none<E>(self) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        Ok(None)
+    }
+
+    fn visit_unit<E>(self) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        Ok(None)
+    }
+
+    fn visit_some<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        self.0.deserialize(deserializer).map(Some)
+    }
+}
+
+
+macro_rules! encoded_binary {
+    ($name:ident, $encoding:expr, $doc:literal) => {
+        #[doc = $doc]
+        ///
+        /// Binary formats keep using raw bytes.
+        /// Optional fields use the `option` submodule and need `#[serde(default)]` to be omitted
+        pub mod $name {
+            use super::{Binary, Deserializer, Encoding};
+            use serde::ser::Serializer;
+
+            pub fn serialize<S: Serializer>(binary: &Binary, serializer: S) -> Result<S::Ok, S::Error> {
+                $encoding.serialize(binary, serializer)
+            }
+
+            pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Binary, D::Error> {
+                $encoding.deserialize(deserializer)
+            }
+
+            pub mod option {
+                use super::{Binary, Deserializer, Encoding, Serializer};
+
+                pub fn serialize<S: Serializer>(binary: &Option<Binary>, serializer: S) -> Result<S::Ok, S::Error> {
+                    $encoding.serialize_option(binary, serializer)
+                }
+
+                pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Option<Binary>, D::Error> {
+                    $encoding.deserialize_option(deserializer)
+                }
+            }
+        }
+    };
+}
+
+encoded_binary!(
+    as_hex, Encoding::Hex, 
+    "Serde adapter for `Binary` fields encoded as hex in JSON e.g. `#[serde(with = \"saa_common::as_hex\")]`. \
+    Accepts strings with or without `0x` prefix and outputs them without it"
+);
+
+encoded_binary!(
+    as_base64url, Encoding::Base64Url, 
+    "Serde adapter for `Binary` fields encoded as base64url in JSON e.g. `#[serde(with = \"saa_common::as_base64url\")]`"
+);
+
+
 #[cfg(feature = "wasm")]
 impl From<Binary> for crate::cosmwasm::Binary {
     fn from(binary: Binary) -> Self {
@@ -293,11 +535,15 @@ pub fn to_json_binary<T>(data: &T) -> Result<Binary, AuthError>
 where
     T: Serialize + ?Sized,
 {   
-    serde_json_wasm::to_vec(data).map_err(|e| AuthError::generic(e.to_string())).map(Binary)
+    serde_json_wasm::to_vec(data).map_err(|e| AuthError::InvalidEncoding(e.to_string())).map(Binary)
 }
 
 
 pub fn from_json<T: DeserializeOwned>(value: impl AsRef<[u8]>) -> Result<T, AuthError> {
     serde_json_wasm::from_slice(value.as_ref())
-        .map_err(|e| AuthError::generic(e.to_string()))
+        .map_err(|e| AuthError::InvalidEncoding(e.to_string()))
 }
+
+
+#[cfg(test)]
+mod tests;
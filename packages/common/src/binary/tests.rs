@@ -0,0 +1,81 @@
+use serde::{Deserialize, Serialize};
+use super::*;
+
+
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+#[serde(deny_unknown_fields)]
+struct Encoded {
+    #[serde(with = "crate::as_hex")]
+    signature: Binary,
+    #[serde(with = "crate::as_base64url")]
+    challenge: Binary,
+    #[serde(default, with = "crate::as_hex::option")]
+    pubkey: Option<Binary>,
+    #[serde(default, with = "crate::as_base64url::option")]
+    user_handle: Option<Binary>,
+    plain: Binary,
+}
+
+
+#[test]
+fn hex_encoding() {
+    let binary = Binary::from([0xde, 0xad, 0xbe, 0xef]);
+    assert_eq!(binary.to_hex(), "deadbeef");
+    assert_eq!(binary.to_prefixed_hex(), "0xdeadbeef");
+
+    assert_eq!(Binary::from_hex("deadbeef").unwrap(), binary);
+    assert_eq!(Binary::from_hex("0xDEADBEEF").unwrap(), binary);
+    assert_eq!(Binary::from_hex("0X").unwrap(), Binary::default());
+
+    assert!(Binary::from_hex("0xdeadbee").is_err());
+    assert!(Binary::from_hex("0xzz").is_err());
+    assert!(Binary::from_hex("00x1").is_err());
+}
+
+
+#[test]
+fn base64url_encoding() {
+    let binary = Binary::from([0xfb, 0xff, 0xbf]);
+    assert_eq!(binary.to_base64(), "+/+/");
+    assert_eq!(binary.to_base64url(), "-_-_");
+    assert_eq!(Binary::from_base64url("-_-_").unwrap(), binary);
+
+    // padding is omitted but accepted
+    let binary = Binary::from(b"challenge");
+    assert_eq!(binary.to_base64(), "Y2hhbGxlbmdl");
+    assert_eq!(Binary::from([1u8]).to_base64url(), "AQ");
+    assert_eq!(Binary::from_base64url("AQ").unwrap(), Binary::from([1u8]));
+    assert_eq!(Binary::from_base64url("AQ==").unwrap(), Binary::from([1u8]));
+
+    // standard alphabet is rejected
+    assert!(Binary::from_base64url("+/+/").is_err());
+}
+
+
+#[test]
+fn serde_adapters() {
+    let value = Encoded {
+        signature: Binary::from([0xab, 0xcd]),
+        challenge: Binary::from([0xfb, 0xff]),
+        pubkey: Some(Binary::from([0x02, 0x01])),
+        user_handle: None,
+        plain: Binary::from([0xfb, 0xff]),
+    };
+    let json = to_json_binary(&value).unwrap();
+    assert_eq!(
+        String::from_utf8(json.to_vec()).unwrap(),
+        r#"{"signature":"abcd","challenge":"-_8","pubkey":"0201","user_handle":null,"plain":"+/8="}"#
+    );
+    assert_eq!(from_json::<Encoded>(&json).unwrap(), value);
+
+    let parsed : Encoded = from_json(
+        r#"{"signature":"0xABCD","challenge":"-_8=","user_handle":"AQ","plain":"+/8="}"#
+    ).unwrap();
+    assert_eq!(parsed.signature, value.signature);
+    assert_eq!(parsed.challenge, value.challenge);
+    assert_eq!(parsed.pubkey, None);
+    assert_eq!(parsed.user_handle, Some(Binary::from([1u8])));
+
+    let err = from_json::<Encoded>(r#"{"signature":"+/8=","challenge":"","plain":""}"#).unwrap_err();
+    assert!(err.to_string().contains("invalid hex"));
+}
@@ -1,25 +1,15 @@
-#[cfg(any(feature = "std", not(feature = "substrate")))]
-use {thiserror::Error, saa_schema::wasm_serde};
-use {crate::String, std::string::FromUtf8Error};
-
-
-#[cfg(all(not(feature = "std"), feature = "substrate"))]
-#[derive(Debug, PartialEq, Eq, Clone, scale::Encode, scale::Decode)]
-pub enum AuthError {
-    NoCredentials,
-    InvalidLength(String),
-    RecoveryParam,
-    RecoveryMismatch,
-    Signature(String),
-    Recovery(String),
-    Generic(String),
-    Crypto(String),
-    SemVer(String),
-}
-
-
-
-#[cfg(any(feature = "std", not(feature = "substrate")))]
+use thiserror::Error;
+use saa_schema::wasm_serde;
+use crate::String;
+
+
+/// Errors of the authentication with stable numeric codes returned by [`AuthError::code`].
+/// The codes are grouped by hundreds and never reused:
+/// - `1xx` malformed or missing data of a credential
+/// - `2xx` failed cryptographic verification
+/// - `3xx` replay protection and the context of the signed data
+/// - `4xx` state of the account and permissions of the credentials
+/// - `9xx` everything else
 #[wasm_serde]
 #[derive(Error)]
 pub enum AuthError {
@@ -35,7 +25,7 @@ pub enum AuthError {
 
     #[error("Values of v other than 27 and 28 not supported. Replay protection (EIP-155) cannot be used here.")]
     RecoveryParam,
-    
+
     #[error("Error recovering from the signature: Addresses do not match")]
     RecoveryMismatch,
 
@@ -49,7 +39,7 @@ pub enum AuthError {
     NonceUsed,
 
     #[error("The given credential was not found on this account")]
-    NotFound, 
+    NotFound,
 
     #[error("The given credential already exists on this account")]
     AlreadyExists,
@@ -71,9 +61,53 @@ pub enum AuthError {
 
     #[error("{0}")]
     Crypto(String),
-    
+
     #[error("Semver parsing error: {0}")]
     SemVer(String),
+
+    // variants below were added after the first release and must stay after the older ones
+    // to keep the binary encodings of the errors stable
+    #[error("The role of the credential doesn't allow this action")]
+    Unauthorized,
+
+    #[error("Spending limit exceeded for {0}")]
+    SpendingLimitExceeded(String),
+
+    #[error("The account is locked")]
+    Locked,
+
+    #[error("The account is not locked")]
+    NotLocked,
+
+    #[error("Invalid public key: {0}")]
+    InvalidPubkey(String),
+
+    #[error("Malformed signature: {0}")]
+    MalformedSignature(String),
+
+    #[error("{0}")]
+    InvalidEncoding(String),
+
+    #[error("{0}")]
+    InvalidData(String),
+
+    #[error("Unsupported credential: {0}")]
+    UnsupportedCredential(String),
+
+    #[error("The signed data doesn't match the expected one")]
+    DigestMismatch,
+
+    #[error("The credential was created for a different origin")]
+    OriginMismatch,
+
+    #[error("The signed data has expired")]
+    Expired,
+
+    #[error("The signed messages don't match the executed ones")]
+    MessagesMismatch,
+
+    #[error("The provided credential was meant for a different account")]
+    AccountMismatch,
 }
 
 
@@ -81,26 +115,67 @@ impl AuthError {
     pub fn generic<M: Into<String>>(msg: M) -> Self {
         AuthError::Generic(msg.into())
     }
+
+    /// Stable code of the error for the clients to map it without matching the messages
+    pub const fn code(&self) -> u16 {
+        match self {
+            AuthError::NoCredentials            => 100,
+            AuthError::MissingData(_)           => 101,
+            AuthError::InvalidLength(_, _)      => 102,
+            AuthError::InvalidPubkey(_)         => 103,
+            AuthError::MalformedSignature(_)    => 104,
+            AuthError::InvalidEncoding(_)       => 105,
+            AuthError::InvalidData(_)           => 106,
+            AuthError::UnsupportedCredential(_) => 107,
+
+            AuthError::Signature(_)             => 200,
+            AuthError::Recovery(_)              => 201,
+            AuthError::RecoveryParam            => 202,
+            AuthError::RecoveryMismatch         => 203,
+            AuthError::DigestMismatch           => 204,
+            AuthError::OriginMismatch           => 205,
+            AuthError::Crypto(_)                => 206,
+
+            AuthError::ChainIdMismatch          => 300,
+            AuthError::ContractMismatch         => 301,
+            AuthError::NonceUsed                => 302,
+            AuthError::DifferentNonce           => 303,
+            AuthError::Expired                  => 304,
+            AuthError::MessagesMismatch         => 305,
+            AuthError::AccountMismatch          => 306,
+
+            AuthError::NotFound                 => 400,
+            AuthError::AlreadyExists            => 401,
+            AuthError::NoVerifying              => 402,
+            AuthError::Unauthorized             => 403,
+            AuthError::Locked                   => 404,
+            AuthError::NotLocked                => 405,
+            AuthError::SpendingLimitExceeded(_) => 406,
+
+            AuthError::Generic(_)               => 900,
+            AuthError::SemVer(_)                => 901,
+        }
+    }
 }
 
 impl From<bech32::primitives::hrp::Error> for AuthError {
     fn from(err: bech32::primitives::hrp::Error) -> Self {
-        Self::Crypto(err.to_string())
+        Self::InvalidEncoding(err.to_string())
     }
 }
 
 
 impl From<bech32::EncodeError> for AuthError {
     fn from(err: bech32::EncodeError) -> Self {
-        Self::Crypto(err.to_string())
+        Self::InvalidEncoding(err.to_string())
     }
 }
 
 
 #[cfg(feature = "std")]
-impl From<FromUtf8Error> for AuthError {
-    fn from(err: FromUtf8Error) -> Self {
-        Self::Recovery(err.to_string())
+impl From<std::string::FromUtf8Error> for AuthError {
+    fn from(err: std::string::FromUtf8Error) -> Self {
+        Self::InvalidEncoding(err.to_string())
     }
 }
 
@@ -108,7 +183,13 @@ impl From<FromUtf8Error> for AuthError {
 #[cfg(feature = "native")] 
 impl From<cosmwasm_crypto::CryptoError> for AuthError {
     fn from(err: cosmwasm_crypto::CryptoError) -> Self {
-        Self::Crypto(err.to_string())
+        use cosmwasm_crypto::CryptoError;
+        match err {
+            CryptoError::InvalidPubkeyFormat { .. } => Self::InvalidPubkey(err.to_string()),
+            CryptoError::InvalidSignatureFormat { .. } => Self::MalformedSignature(err.to_string()),
+            CryptoError::InvalidRecoveryParam { .. } => Self::RecoveryParam,
+            _ => Self::Crypto(err.to_string()),
+        }
     }
 }
 
@@ -118,7 +199,12 @@ mod implementation{
 
     impl From<crate::cosmwasm::RecoverPubkeyError> for AuthError {
         fn from(err: crate::cosmwasm::RecoverPubkeyError) -> Self {
-            Self::Recovery(err.to_string())
+            use crate::cosmwasm::RecoverPubkeyError;
+            match err {
+                RecoverPubkeyError::InvalidSignatureFormat => Self::MalformedSignature(err.to_string()),
+                RecoverPubkeyError::InvalidRecoveryParam => Self::RecoveryParam,
+                _ => Self::Recovery(err.to_string()),
+            }
         }
     }
 
@@ -130,7 +216,17 @@ mod implementation{
 
     impl From<crate::cosmwasm::VerificationError> for AuthError {
         fn from(err: crate::cosmwasm::VerificationError) -> Self {
-            Self::Crypto(err.to_string())
+            use crate::cosmwasm::VerificationError;
+            match err {
+                VerificationError::InvalidPubkeyFormat => Self::InvalidPubkey(err.to_string()),
+                VerificationError::InvalidSignatureFormat => Self::MalformedSignature(err.to_string()),
+                VerificationError::InvalidRecoveryParam => Self::RecoveryParam,
+                _ => Self::Crypto(err.to_string()),
+            }
         }
     }
 }
+
+
+#[cfg(test)]
+mod tests;
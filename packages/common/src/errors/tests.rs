@@ -0,0 +1,99 @@
+use crate::{String, Vec};
+use super::*;
+
+
+/// Every variant in the order of the declaration together with its code.
+/// Changing any of the codes breaks the clients mapping them
+fn all_errors() -> Vec<(AuthError, u16)> {
+    let msg = || String::from("msg");
+    vec![
+        (AuthError::NoCredentials,                 100),
+        (AuthError::MissingData(msg()),            101),
+        (AuthError::InvalidLength(32, 31),         102),
+        (AuthError::RecoveryParam,                 202),
+        (AuthError::RecoveryMismatch,              203),
+        (AuthError::ChainIdMismatch,               300),
+        (AuthError::ContractMismatch,              301),
+        (AuthError::NonceUsed,                     302),
+        (AuthError::NotFound,                      400),
+        (AuthError::AlreadyExists,                 401),
+        (AuthError::NoVerifying,                   402),
+        (AuthError::DifferentNonce,                303),
+        (AuthError::Signature(msg()),              200),
+        (AuthError::Recovery(msg()),               201),
+        (AuthError::Generic(msg()),                900),
+        (AuthError::Crypto(msg()),                 206),
+        (AuthError::SemVer(msg()),                 901),
+        (AuthError::Unauthorized,                  403),
+        (AuthError::SpendingLimitExceeded(msg()),  406),
+        (AuthError::Locked,                        404),
+        (AuthError::NotLocked,                     405),
+        (AuthError::InvalidPubkey(msg()),          103),
+        (AuthError::MalformedSignature(msg()),     104),
+        (AuthError::InvalidEncoding(msg()),        105),
+        (AuthError::InvalidData(msg()),            106),
+        (AuthError::UnsupportedCredential(msg()),  107),
+        (AuthError::DigestMismatch,                204),
+        (AuthError::OriginMismatch,                205),
+        (AuthError::Expired,                       304),
+        (AuthError::MessagesMismatch,              305),
+        (AuthError::AccountMismatch,               306),
+    ]
+}
+
+
+#[test]
+fn codes_are_unique_and_grouped() {
+    let mut codes = all_errors().iter().map(|(e, _)| e.code()).collect::<Vec<_>>();
+    let len = codes.len();
+    codes.sort();
+    codes.dedup();
+    assert_eq!(codes.len(), len, "codes must be unique");
+    assert!(codes.iter().all(|c| [1, 2, 3, 4, 9].contains(&(c / 100))));
+}
+
+
+#[test]
+fn codes_are_stable() {
+    for (error, code) in all_errors() {
+        assert_eq!(error.code(), code, "code of {:?} has changed", error);
+    }
+}
+
+
+#[test]
+fn all_variants_are_listed() {
+    // fails to compile when a variant is added without being listed above
+    for (error, _) in all_errors() {
+        match error {
+            AuthError::NoCredentials | AuthError::MissingData(_) | AuthError::InvalidLength(_, _) |
+            AuthError::RecoveryParam | AuthError::RecoveryMismatch | AuthError::ChainIdMismatch |
+            AuthError::ContractMismatch | AuthError::NonceUsed | AuthError::NotFound |
+            AuthError::AlreadyExists | AuthError::NoVerifying | AuthError::DifferentNonce |
+            AuthError::Signature(_) | AuthError::Recovery(_) | AuthError::Generic(_) |
+            AuthError::Crypto(_) | AuthError::SemVer(_) | AuthError::Unauthorized |
+            AuthError::SpendingLimitExceeded(_) | AuthError::Locked | AuthError::NotLocked |
+            AuthError::InvalidPubkey(_) | AuthError::MalformedSignature(_) | AuthError::InvalidEncoding(_) |
+            AuthError::InvalidData(_) | AuthError::UnsupportedCredential(_) | AuthError::DigestMismatch |
+            AuthError::OriginMismatch | AuthError::Expired | AuthError::MessagesMismatch |
+            AuthError::AccountMismatch => {}
+        }
+    }
+    assert_eq!(all_errors().len(), 31);
+}
+
+
+#[cfg(feature = "wasm")]
+#[test]
+fn verification_errors_are_typed() {
+    use crate::cosmwasm::{RecoverPubkeyError, VerificationError};
+
+    assert_eq!(AuthError::from(VerificationError::InvalidPubkeyFormat).code(), 103);
+    assert_eq!(AuthError::from(VerificationError::InvalidSignatureFormat).code(), 104);
+    assert_eq!(AuthError::from(VerificationError::InvalidRecoveryParam), AuthError::RecoveryParam);
+    assert_eq!(AuthError::from(VerificationError::GenericErr).code(), 206);
+    assert_eq!(AuthError::from(RecoverPubkeyError::InvalidSignatureFormat).code(), 104);
+    assert_eq!(AuthError::from(RecoverPubkeyError::InvalidHashFormat).code(), 201);
+
+    assert_eq!(crate::Binary::from_hex("0xzz").unwrap_err().code(), 105);
+}
@@ -7,7 +7,7 @@ pub mod utils;
 pub mod messages;
 pub mod hashes;
 pub use errors::*;
-pub use binary::{Binary, to_json_binary, from_json};
+pub use binary::{Binary, to_json_binary, from_json, as_hex, as_base64url};
 
 
 #[cfg(not(feature = "native"))]
@@ -16,6 +16,12 @@ mod identity;
 #[cfg(feature = "storage")]
 pub mod storage;
 
+#[cfg(all(feature = "cosmwasm", feature = "spending"))]
+pub mod spending;
+
+#[cfg(all(feature = "wasm", feature = "audit"))]
+pub mod audit;
+
 
 
 #[cfg(any(feature = "std", not(feature = "substrate")))]
@@ -42,28 +48,32 @@ pub mod crypto {
 #[cfg(feature = "cosmwasm")]
 pub mod cosmwasm {
     pub use cosmwasm_std::{
-        Api, Env, Addr, CanonicalAddr, MessageInfo, Binary,
+        Api, Env, Addr, CanonicalAddr, MessageInfo, Binary, Event,
         from_json, to_json_binary, CustomMsg,
         StdError, VerificationError, RecoverPubkeyError
     };
     #[cfg(feature = "storage")]
     pub use cosmwasm_std::Storage;
-    #[cfg(feature = "iterator")]
+    #[cfg(feature = "storage")]
     pub use cosmwasm_std::Order;
+    #[cfg(any(feature = "replay", feature = "spending"))]
+    pub use cosmwasm_std::CosmosMsg;
+    #[cfg(feature = "spending")]
+    pub use cosmwasm_std::{BankMsg, WasmMsg, Coin, Uint128};
 }
 
 
 #[cfg(all(feature = "secretwasm", not(feature = "cosmwasm")))]
 pub mod cosmwasm {
     pub use secretwasm_std::{
-        Api, Env, Addr, CanonicalAddr, MessageInfo, Binary,
+        Api, Env, Addr, CanonicalAddr, MessageInfo, Binary, Event,
         from_binary as from_json, to_binary as to_json_binary,
         StdError, VerificationError, RecoverPubkeyError,
         CustomMsg
     };
     #[cfg(feature = "storage")]
     pub use secretwasm_std::Storage;
-    #[cfg(feature = "iterator")]
+    #[cfg(feature = "storage")]
     pub use secretwasm_std::Order;
 }
 
@@ -156,6 +166,98 @@ pub struct CredentialInfo {
     pub hrp: Option<String>,
     /// extension data
     pub extension: Option<Binary>,
+    /// human readable label to tell the credentials apart e.g. "MacBook Touch ID"
+    pub label: Option<String>,
+    /// block at which the credential has been added to the account
+    pub created_at: Option<BlockStamp>,
+    /// block of the last signed action authorised by the credential
+    pub last_used: Option<BlockStamp>,
+    /// permissions of the credential. Treated as admin if not set
+    pub role: Option<CredentialRole>,
+}
+
+
+impl CredentialInfo {
+    pub fn role(&self) -> CredentialRole {
+        self.role.clone().unwrap_or(CredentialRole::Admin)
+    }
+}
+
+
+#[wasm_serde]
+pub enum CredentialRole {
+    /// Full control over the account including the management of its credentials
+    Admin,
+    /// Can authorise signed actions and queries but not updates of the credentials
+    Executor,
+    /// Can only authorise signed queries
+    Viewer,
+    /// Can only lock the account in case of emergency
+    Guardian,
+}
+
+
+impl CredentialRole {
+    pub fn can_update(&self) -> bool {
+        *self == CredentialRole::Admin
+    }
+
+    pub fn can_execute(&self) -> bool {
+        *self == CredentialRole::Admin || *self == CredentialRole::Executor
+    }
+
+    pub fn can_query(&self) -> bool {
+        *self != CredentialRole::Guardian
+    }
+}
+
+
+#[wasm_serde]
+pub struct BlockStamp {
+    /// block height
+    pub height: u64,
+    /// block time in seconds
+    pub time: u64,
+}
+
+
+#[wasm_serde]
+pub struct AccountLock {
+    /// id of the credential that locked the account
+    pub locked_by: Binary,
+    /// block at which the account has been locked
+    pub locked_at: BlockStamp,
+}
+
+
+/// Requirements for lifting a lock from the account
+#[wasm_serde]
+pub struct LockPolicy {
+    /// number of distinct admin credentials that can unlock the account at any time
+    pub threshold: u8,
+    /// seconds after which a single admin credential can unlock the account
+    pub delay: u64,
+}
+
+
+impl Default for LockPolicy {
+    fn default() -> Self {
+        Self {
+            threshold: 2,
+            delay: 2 * 24 * 60 * 60,
+        }
+    }
+}
+
+
+#[cfg(feature = "wasm")]
+impl From<&Env> for BlockStamp {
+    fn from(env: &Env) -> Self {
+        Self {
+            height: env.block.height,
+            time: env.block.time.seconds(),
+        }
+    }
 }
 
 
@@ -169,6 +271,26 @@ pub enum CredentialName {
     Secp256k1,
     Secp256r1,
     Ed25519,
+    Custom,
+    Siwe,
+}
+
+
+impl CredentialName {
+    /// Name of the credential as it appears in the serialized messages
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            CredentialName::Caller => "caller",
+            CredentialName::CosmosArbitrary => "cosmos_arbitrary",
+            CredentialName::EthPersonalSign => "eth_personal_sign",
+            CredentialName::Passkey => "passkey",
+            CredentialName::Secp256k1 => "secp256k1",
+            CredentialName::Secp256r1 => "secp256r1",
+            CredentialName::Ed25519 => "ed25519",
+            CredentialName::Custom => "custom",
+            CredentialName::Siwe => "siwe",
+        }
+    }
 }
 
 
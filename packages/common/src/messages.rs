@@ -24,15 +24,15 @@ impl<E> AuthPayload<E> {
         if self.hrp.is_some() {
             ensure!(
                 self.address.is_none(),
-                AuthError::generic(error)
+                AuthError::InvalidData(error.to_string())
             );
         }
         if self.address.is_some() {
-            ensure!(self.hrp.is_none(), AuthError::generic(error));
+            ensure!(self.hrp.is_none(), AuthError::InvalidData(error.to_string()));
             let addr = self.address.clone().unwrap();
             ensure!(
                 addr.len() > 3 && (addr.starts_with("0x") || addr.contains("1")),
-                AuthError::generic("Invalid address")
+                AuthError::InvalidData("Invalid address".to_string())
             );
         }
         Ok(())
@@ -58,7 +58,7 @@ impl<E> AuthPayload<E> {
                 let name = info_res.unwrap().name;
                 ensure!(
                     name == crate::CredentialName::CosmosArbitrary || name == crate::CredentialName::Secp256k1,
-                    AuthError::generic("'hrp' can only be passed for 'cosmos-arbitrary' or 'secp256k1'")
+                    AuthError::InvalidData("'hrp' can only be passed for 'cosmos-arbitrary' or 'secp256k1'".to_string())
                 );
             }
         }
@@ -74,6 +74,9 @@ pub struct MsgDataToSign<M = String> {
     pub contract_address: String,
     pub messages: Vec<M>,
     pub nonce: String,
+    /// Id of the account when the contract hosts several of them in namespaced storages
+    #[cfg_attr(any(feature = "cosmwasm", feature = "wasm"), serde(default, skip_serializing_if = "Option::is_none"))]
+    pub account_id: Option<String>,
 }
 
 
@@ -96,6 +99,8 @@ pub struct MsgDataToVerify {
     pub chain_id: String,
     pub contract_address: String,
     pub nonce: String,
+    #[cfg_attr(feature = "wasm", serde(default, skip_serializing_if = "Option::is_none"))]
+    pub account_id: Option<String>,
 }
 
 
@@ -105,6 +110,7 @@ impl<M> Into<MsgDataToVerify> for &MsgDataToSign<M> {
             chain_id: self.chain_id.clone(),
             contract_address: self.contract_address.clone(),
             nonce: self.nonce.clone(),
+            account_id: self.account_id.clone(),
         }
     }
 }
@@ -122,8 +128,11 @@ impl MsgDataToVerify {
         ensure!(self.contract_address == env.contract.address.to_string(), AuthError::ContractMismatch);
         ensure!(self.nonce.len() > 0, AuthError::MissingData("Nonce".to_string()));
         #[cfg(feature = "replay")]
-        ensure!(crate::storage::ACCOUNT_NUMBER.load(store)
-            .unwrap_or_default().to_string() == self.nonce, AuthError::DifferentNonce);
+        {
+            ensure!(crate::storage::ACCOUNT_NUMBER.load(store)
+                .unwrap_or_default().to_string() == self.nonce, AuthError::DifferentNonce);
+            ensure!(self.account_id == crate::storage::namespaced_account(store), AuthError::AccountMismatch);
+        }
         Ok(())
     }
 }
@@ -0,0 +1,143 @@
+use crate::cosmwasm::{BankMsg, Coin, CosmosMsg, Env, Storage, Uint128, WasmMsg};
+use saa_schema::wasm_serde;
+
+use crate::{ensure, format, AuthError, CredentialId, String, Vec};
+use crate::storage::{SPENDING_LIMITS, SPENDING_RECORDS};
+
+
+#[wasm_serde]
+pub struct SpendingLimit {
+    /// denomination of the native token
+    pub denom: String,
+    /// maximum amount that can be spent during the period
+    pub amount: Uint128,
+    /// length of the rolling window in seconds
+    pub period: u64,
+}
+
+
+/// Amount of a token spent by a credential at the given time
+#[wasm_serde]
+pub struct SpendRecord {
+    /// block time in seconds
+    pub time: u64,
+    pub amount: Uint128,
+}
+
+
+pub fn set_spending_limits(
+    storage: &mut dyn Storage,
+    id: &CredentialId,
+    limits: Vec<SpendingLimit>,
+) -> Result<(), AuthError> {
+    for (i, limit) in limits.iter().enumerate() {
+        ensure!(limit.period > 0, AuthError::InvalidData("Spending period must be greater than 0".to_string()));
+        ensure!(
+            limits.iter().skip(i + 1).all(|l| l.denom != limit.denom),
+            AuthError::InvalidData(format!("Duplicate spending limit for {}", limit.denom))
+        );
+    }
+    if limits.is_empty() {
+        SPENDING_LIMITS.remove(storage, id.clone());
+    } else {
+        SPENDING_LIMITS.save(storage, id.clone(), &limits)?;
+    }
+    Ok(())
+}
+
+
+pub fn get_spending_limits(
+    storage: &dyn Storage,
+    id: &CredentialId,
+) -> Result<Option<Vec<SpendingLimit>>, AuthError> {
+    Ok(SPENDING_LIMITS.may_load(storage, id.clone())?)
+}
+
+
+/// Remove the limits and the history of spendings of a credential
+pub fn remove_spending_limits(
+    storage: &mut dyn Storage,
+    id: &CredentialId,
+) -> Result<(), AuthError> {
+    if let Some(limits) = SPENDING_LIMITS.may_load(storage, id.clone())? {
+        for limit in limits {
+            SPENDING_RECORDS.remove(storage, (id.as_slice(), limit.denom.as_str()));
+        }
+        SPENDING_LIMITS.remove(storage, id.clone());
+    }
+    Ok(())
+}
+
+
+/// Native tokens leaving the account with the given messages.
+/// Fails on messages that can't be inspected such as custom or stargate ones.
+/// Only the funds of executions are counted and not the tokens that their messages move e.g. CW20 transfers
+pub fn spent_funds<T>(msgs: &[CosmosMsg<T>]) -> Result<Vec<Coin>, AuthError> {
+    let mut spent : Vec<Coin> = Vec::new();
+    for msg in msgs {
+        let coins = match msg {
+            CosmosMsg::Bank(BankMsg::Send { amount, .. }) => amount.clone(),
+            CosmosMsg::Bank(BankMsg::Burn { amount }) => amount.clone(),
+            CosmosMsg::Wasm(WasmMsg::Execute { funds, .. }) => funds.clone(),
+            CosmosMsg::Wasm(WasmMsg::Instantiate { funds, .. }) => funds.clone(),
+            // migrations and admin changes would give control over the contracts of the account
+            _ => return Err(AuthError::generic(
+                "Only bank sends, burns, executions and instantiations are allowed for credentials with spending limits"
+            )),
+        };
+        for coin in coins {
+            match spent.iter_mut().find(|c| c.denom == coin.denom) {
+                Some(c) => c.amount = c.amount
+                    .checked_add(coin.amount)
+                    .map_err(|_| AuthError::SpendingLimitExceeded(coin.denom.clone()))?,
+                None => spent.push(coin),
+            }
+        }
+    }
+    Ok(spent)
+}
+
+
+/// Check that the messages stay within the spending limits of the credential and record the spendings.
+/// Credentials without limits are unrestricted. Credentials with limits can only spend the listed denominations
+/// of the native tokens while the tokens of other contracts are not limited
+pub fn assert_spending<T>(
+    storage: &mut dyn Storage,
+    env: &Env,
+    id: &CredentialId,
+    msgs: &[CosmosMsg<T>],
+) -> Result<(), AuthError> {
+    let limits = match SPENDING_LIMITS.may_load(storage, id.clone())? {
+        Some(limits) => limits,
+        None => return Ok(()),
+    };
+    let now = env.block.time.seconds();
+
+    for coin in spent_funds(msgs)? {
+        if coin.amount.is_zero() {
+            continue;
+        }
+        let limit = limits
+            .iter()
+            .find(|l| l.denom == coin.denom)
+            .ok_or(AuthError::SpendingLimitExceeded(coin.denom.clone()))?;
+
+        let key = (id.as_slice(), coin.denom.as_str());
+        let mut records : Vec<SpendRecord> = SPENDING_RECORDS
+            .may_load(storage, key)?
+            .unwrap_or_default()
+            .into_iter()
+            .filter(|r| r.time.saturating_add(limit.period) > now)
+            .collect();
+
+        let spent = records
+            .iter()
+            .try_fold(coin.amount, |acc, r| acc.checked_add(r.amount))
+            .map_err(|_| AuthError::SpendingLimitExceeded(coin.denom.clone()))?;
+        ensure!(spent <= limit.amount, AuthError::SpendingLimitExceeded(coin.denom.clone()));
+
+        records.push(SpendRecord { time: now, amount: coin.amount });
+        SPENDING_RECORDS.save(storage, key, &records)?;
+    }
+    Ok(())
+}
@@ -10,14 +10,35 @@ pub use cosmwasm::*;
 #[cfg(all(feature = "secretwasm", not(feature = "cosmwasm")))]
 pub use secretwasm::*;
 
+#[cfg(feature = "wasm")]
+mod namespace;
+
+#[cfg(feature = "wasm")]
+mod migration;
+
+#[cfg(feature = "wasm")]
+pub use migration::{migrate_storage, get_storage_version, set_storage_version, CURRENT_STORAGE_VERSION};
+
+#[cfg(feature = "wasm")]
+pub use namespace::{AccountStorage, ReadonlyAccountStorage, account_prefix, namespaced_account};
+
+#[cfg(all(test, feature = "cosmwasm", feature = "iterator"))]
+mod tests;
+
 
 #[cfg(feature = "wasm")]
 mod inner {
 
     use super::*;
-    use crate::{AuthError, CredentialInfo};
+    use crate::{AccountLock, AuthError, CredentialInfo, LockPolicy};
     use crate::cosmwasm::StdError;
 
+    /// Number of items returned by paginated queries when no limit is given
+    pub const DEFAULT_LIMIT: u32 = 10;
+
+    /// Maximum number of items that can be returned by a single paginated query
+    pub const MAX_LIMIT: u32 = 30;
+
     #[cfg(feature = "replay")]
     pub fn increment_account_number(
         storage: &mut dyn crate::cosmwasm::Storage
@@ -59,11 +80,7 @@ mod inner {
         .range(storage, None, None, crate::cosmwasm::Order::Ascending)
         .map(|item| {
             let (id, info) = item?;
-            Ok((id.into(), CredentialInfo {
-                name: info.name,
-                hrp: info.hrp,
-                extension: info.extension,
-            }))
+            Ok((id.into(), info))
         })
         .collect::<Result<Vec<(crate::Binary, CredentialInfo)>, AuthError>>()?;
         Ok(credentials)
@@ -77,19 +94,107 @@ mod inner {
         .iter(storage)?
         .map(|item| {
             let (id, info) = item?;
-            Ok((
-                crate::Binary::new(id), 
-                CredentialInfo {
-                    name: info.name,
-                    hrp: info.hrp,
-                    extension: info.extension,
-            }))
+            Ok((crate::Binary::new(id), info))
         })
         .collect::<Result<Vec<(crate::Binary, CredentialInfo)>, AuthError>>()?;
 
         Ok(credentials)
     }
 
+
+    /// Credentials with ids after `start_after` in ascending order, optionally of a single type only.
+    /// Never skips stored credentials: a page filtered by type goes through as many of them as needed
+    #[cfg(all(feature = "cosmwasm", feature = "iterator"))]
+    pub fn get_credentials_paginated(
+        storage: &dyn cosmwasm_std::Storage,
+        start_after: Option<crate::CredentialId>,
+        limit: Option<u32>,
+        name: Option<crate::CredentialName>,
+    ) -> Result<Vec<(crate::Binary, CredentialInfo)>, AuthError> {
+        let limit = limit.unwrap_or(DEFAULT_LIMIT).min(MAX_LIMIT) as usize;
+        let start = start_after.map(cw_storage_plus::Bound::exclusive);
+
+        cosmwasm::CREDENTIAL_INFOS
+        .range(storage, start, None, crate::cosmwasm::Order::Ascending)
+        .filter(|item| match (item, &name) {
+            (Ok((_, info)), Some(name)) => info.name == *name,
+            _ => true
+        })
+        .take(limit)
+        .map(|item| {
+            let (id, info) = item?;
+            Ok((id.into(), info))
+        })
+        .collect()
+    }
+
+    /// Credentials with ids after `start_after` in ascending order, optionally of a single type only.
+    /// Never skips stored credentials: a page filtered by type goes through as many of them as needed
+    #[cfg(all(feature = "secretwasm", not(feature = "cosmwasm"), feature = "iterator"))]
+    pub fn get_credentials_paginated(
+        storage: &dyn secretwasm_std::Storage,
+        start_after: Option<crate::CredentialId>,
+        limit: Option<u32>,
+        name: Option<crate::CredentialName>,
+    ) -> Result<Vec<(crate::Binary, CredentialInfo)>, AuthError> {
+        let limit = limit.unwrap_or(DEFAULT_LIMIT).min(MAX_LIMIT) as usize;
+        // keymap moves the last item into the slot of a removed one so the position of an item
+        // isn't stable. All the ids are sorted to page over them the same way as with cosmwasm
+        let mut ids = secretwasm::CREDENTIAL_INFOS
+            .iter_keys(storage)?
+            .collect::<Result<Vec<_>, _>>()?;
+        ids.sort();
+
+        let mut credentials = Vec::with_capacity(limit);
+        for id in ids {
+            if credentials.len() == limit {
+                break;
+            }
+            if start_after.as_ref().is_some_and(|start| id <= *start) {
+                continue;
+            }
+            let info = secretwasm::CREDENTIAL_INFOS.get(storage, &id).ok_or(AuthError::NotFound)?;
+            if name.as_ref().is_some_and(|name| info.name != *name) {
+                continue;
+            }
+            credentials.push((crate::Binary::new(id), info));
+        }
+        Ok(credentials)
+    }
+
+
+    #[cfg(all(feature = "cosmwasm", feature = "iterator"))]
+    pub fn count_credentials(
+        storage: &dyn cosmwasm_std::Storage,
+        name: Option<crate::CredentialName>,
+    ) -> Result<u32, AuthError> {
+        let count = match name {
+            Some(name) => cosmwasm::CREDENTIAL_INFOS
+                .range(storage, None, None, crate::cosmwasm::Order::Ascending)
+                .filter(|item| item.as_ref().is_ok_and(|(_, info)| info.name == name))
+                .count(),
+            None => cosmwasm::CREDENTIAL_INFOS
+                .keys_raw(storage, None, None, crate::cosmwasm::Order::Ascending)
+                .count(),
+        };
+        Ok(count as u32)
+    }
+
+    #[cfg(all(feature = "secretwasm", not(feature = "cosmwasm"), feature = "iterator"))]
+    pub fn count_credentials(
+        storage: &dyn secretwasm_std::Storage,
+        name: Option<crate::CredentialName>,
+    ) -> Result<u32, AuthError> {
+        let count = match name {
+            Some(name) => secretwasm::CREDENTIAL_INFOS
+                .iter(storage)?
+                .filter(|item| item.as_ref().is_ok_and(|(_, info)| info.name == name))
+                .count() as u32,
+            None => secretwasm::CREDENTIAL_INFOS.get_len(storage)?,
+        };
+        Ok(count)
+    }
+
    pub  fn save_credential(
         storage: &mut dyn crate::cosmwasm::Storage,
         id: &crate::CredentialId,
@@ -102,6 +207,17 @@ mod inner {
         Ok(())
     }
 
+    /// Record the block of the latest successful authentication with the credential
+    pub fn update_last_used(
+        storage: &mut dyn crate::cosmwasm::Storage,
+        id: &crate::CredentialId,
+        env: &crate::cosmwasm::Env
+    ) -> Result<(), AuthError> {
+        let mut info = get_cred_info(storage, id.clone())?;
+        info.last_used = Some(env.into());
+        save_credential(storage, id, &info)
+    }
+
     pub fn has_credential(
         storage: &dyn crate::cosmwasm::Storage,
         id: &crate::CredentialId
@@ -124,6 +240,36 @@ mod inner {
     }
 
 
+
+    pub fn get_account_lock(
+        storage: &dyn crate::cosmwasm::Storage
+    ) -> Result<Option<AccountLock>, AuthError> {
+        Ok(ACCOUNT_LOCK.may_load(storage)?)
+    }
+
+    pub fn ensure_unlocked(
+        storage: &dyn crate::cosmwasm::Storage
+    ) -> Result<(), AuthError> {
+        crate::ensure!(get_account_lock(storage)?.is_none(), AuthError::Locked);
+        Ok(())
+    }
+
+    pub fn get_lock_policy(
+        storage: &dyn crate::cosmwasm::Storage
+    ) -> Result<LockPolicy, AuthError> {
+        Ok(LOCK_POLICY.may_load(storage)?.unwrap_or_default())
+    }
+
+    pub fn set_lock_policy(
+        storage: &mut dyn crate::cosmwasm::Storage,
+        policy: &LockPolicy
+    ) -> Result<(), AuthError> {
+        crate::ensure!(policy.threshold > 0, AuthError::InvalidData("Unlock threshold must be greater than 0".to_string()));
+        LOCK_POLICY.save(storage, policy)?;
+        Ok(())
+    }
+
+
 }
 #[cfg(feature = "wasm")]
 pub use inner::*;
\ No newline at end of file
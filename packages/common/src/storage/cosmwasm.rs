@@ -1,7 +1,10 @@
 #![allow(dead_code)]
 
 use cw_storage_plus::{Item, Map};
-use crate::{CredentialInfo, CredentialId};
+use crate::{AccountLock, CredentialInfo, CredentialId, LockPolicy};
+
+/// Version of the layout of the stored data
+pub const STORAGE_VERSION : Item<u16> = Item::new("saa_version");
 
 /// The credential ID to use by default for verifications
 pub const VERIFYING_CRED_ID : Item<CredentialId> = Item::new("saa_verifying_id");
@@ -14,8 +17,30 @@ pub const CREDENTIAL_INFOS: Map<CredentialId, CredentialInfo> = Map::new("saa_cr
 /// An EOA address that is authorized to actions withoit any signature
 pub const CALLER : Item<Option<String>> = Item::new("saa_with_caller");
 
+/// Set when the account is locked and all the signed actions are disabled
+pub const ACCOUNT_LOCK : Item<AccountLock> = Item::new("saa_lock");
+
+/// Custom requirements for unlocking the account. Default policy is used if not set
+pub const LOCK_POLICY : Item<LockPolicy> = Item::new("saa_lock_policy");
+
 /// Storage of used nonces  to prevent replay attacks. &str to boolean
 #[cfg(feature = "replay")]
 pub const ACCOUNT_NUMBER : Item<u128> = Item::new("saa_acc_num");
 
 
+
+/// Spending limits of credentials that aren't allowed to move funds freely
+#[cfg(feature = "spending")]
+pub const SPENDING_LIMITS: Map<CredentialId, Vec<crate::spending::SpendingLimit>> = Map::new("saa_spend_limits");
+
+/// Recent spendings of a credential in a given denomination
+#[cfg(feature = "spending")]
+pub const SPENDING_RECORDS: Map<(&[u8], &str), Vec<crate::spending::SpendRecord>> = Map::new("saa_spend_records");
+
+/// Ring buffer of the latest signed actions under slots of `sequence % AUDIT_CAPACITY`
+#[cfg(feature = "audit")]
+pub const AUDIT_LOG: Map<u64, crate::audit::AuditRecord> = Map::new("saa_audit");
+
+/// Total number of recorded actions and the sequence number of the next record
+#[cfg(feature = "audit")]
+pub const AUDIT_COUNT : Item<u64> = Item::new("saa_audit_count");
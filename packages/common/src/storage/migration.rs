@@ -0,0 +1,112 @@
+use crate::{cosmwasm::Storage, ensure, AuthError};
+use super::*;
+
+#[cfg(feature = "secretwasm")]
+use crate::{Binary, CredentialInfo, CredentialName, String};
+
+
+/// Version of the layout of the `saa_*` keys written by this release of the library.
+/// Accounts without a stored version are treated as version 0
+pub const CURRENT_STORAGE_VERSION: u16 = 1;
+
+
+pub fn get_storage_version(
+    storage: &dyn Storage
+) -> Result<u16, AuthError> {
+    Ok(STORAGE_VERSION.may_load(storage)?.unwrap_or_default())
+}
+
+
+pub fn set_storage_version(
+    storage: &mut dyn Storage
+) -> Result<(), AuthError> {
+    STORAGE_VERSION.save(storage, &CURRENT_STORAGE_VERSION)?;
+    Ok(())
+}
+
+
+/// Upgrade the credentials and the account data stored by older releases to the current layout.
+/// Meant to be called from the `migrate` entry point of a contract. Returns the previous version
+pub fn migrate_storage(
+    storage: &mut dyn Storage
+) -> Result<u16, AuthError> {
+    let version = get_storage_version(storage)?;
+    ensure!(
+        version <= CURRENT_STORAGE_VERSION, 
+        AuthError::generic("Stored data is newer than the library")
+    );
+    if version == 0 {
+        migrate_v0(storage)?;
+    }
+    set_storage_version(storage)?;
+    Ok(version)
+}
+
+
+/// Releases before versioning could leave the caller empty or set to an empty string,
+/// didn't initialise the account number and stored credential infos without the extension and metadata
+fn migrate_v0(
+    storage: &mut dyn Storage
+) -> Result<(), AuthError> {
+    let caller = CALLER.load(storage).ok().flatten().filter(|c| !c.is_empty());
+    CALLER.save(storage, &caller)?;
+
+    #[cfg(feature = "replay")]
+    if ACCOUNT_NUMBER.may_load(storage)?.is_none() {
+        ACCOUNT_NUMBER.save(storage, &0u128)?;
+    }
+
+    // keymaps of secret network can always be iterated and their old values must be rewritten
+    #[cfg(any(feature = "iterator", all(feature = "secretwasm", not(feature = "cosmwasm"))))]
+    {
+        #[cfg(feature = "cosmwasm")]
+        let credentials = get_credentials(storage)?;
+        #[cfg(all(feature = "secretwasm", not(feature = "cosmwasm")))]
+        let credentials = secretwasm::LEGACY_CREDENTIAL_INFOS
+            .iter(storage)?
+            .map(|item| item.map(|(id, info)| (Binary::new(id), CredentialInfo::from(info))))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        // re-write the infos in the current shape with the new fields explicitly set
+        for (id, info) in credentials.iter() {
+            save_credential(storage, &id.to_vec(), info)?;
+        }
+        if VERIFYING_CRED_ID.may_load(storage)?.is_none() {
+            let verifying = credentials
+                .iter()
+                .find(|(_, info)| info.name != crate::CredentialName::Caller && info.role().can_update());
+            if let Some((id, _)) = verifying {
+                VERIFYING_CRED_ID.save(storage, &id.to_vec())?;
+            }
+        }
+    }
+    Ok(())
+}
+
+
+/// Credential info as stored before versioning. Secret network keeps the values in bincode that
+/// can't skip the missing fields, so the old infos must be decoded in their own shape.
+/// Binary data couldn't be serialized with bincode at that time so the extension is always empty
+#[cfg(feature = "secretwasm")]
+#[derive(serde::Serialize, serde::Deserialize)]
+pub struct LegacyCredentialInfo {
+    pub name: CredentialName,
+    pub hrp: Option<String>,
+    pub extension: Option<Binary>,
+}
+
+
+#[cfg(feature = "secretwasm")]
+impl From<LegacyCredentialInfo> for CredentialInfo {
+    fn from(info: LegacyCredentialInfo) -> Self {
+        CredentialInfo {
+            name: info.name,
+            hrp: info.hrp,
+            extension: info.extension,
+            label: None,
+            created_at: None,
+            last_used: None,
+            role: None,
+        }
+    }
+}
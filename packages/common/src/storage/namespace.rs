@@ -0,0 +1,165 @@
+use crate::{cosmwasm::{Order, Storage}, Vec};
+
+/// Prefix shared by all the namespaced accounts to avoid clashes with other keys of a contract
+const ACCOUNTS_NAMESPACE: &[u8] = b"saa_accounts";
+
+/// Reserved key under which the namespaced views expose the id of their account
+const ACCOUNT_ID_KEY: &[u8] = b"saa_account_id";
+
+
+/// Build a storage prefix for an account in a form of `saa_accounts | len(id) | id`
+/// Length prefix makes sure that no account id can be a prefix of another
+pub fn account_prefix(account_id: &str) -> Vec<u8> {
+    let id = account_id.as_bytes();
+    let len = id.len() as u16;
+    let mut prefix = Vec::with_capacity(ACCOUNTS_NAMESPACE.len() + 2 + id.len());
+    prefix.extend_from_slice(ACCOUNTS_NAMESPACE);
+    prefix.extend_from_slice(&len.to_be_bytes());
+    prefix.extend_from_slice(id);
+    prefix
+}
+
+
+/// Id of the account if the storage is a namespaced view or `None` for the storage of a contract.
+/// Used by the replay protection to reject messages signed for another account of the same contract
+pub fn namespaced_account(storage: &dyn Storage) -> Option<String> {
+    storage.get(ACCOUNT_ID_KEY).and_then(|id| String::from_utf8(id).ok())
+}
+
+
+fn prefixed(prefix: &[u8], key: &[u8]) -> Vec<u8> {
+    let mut k = prefix.to_vec();
+    k.extend_from_slice(key);
+    k
+}
+
+
+/// Smallest key that is greater than every key starting with the given prefix
+fn prefix_end(prefix: &[u8]) -> Option<Vec<u8>> {
+    let mut end = prefix.to_vec();
+    while let Some(last) = end.pop() {
+        if last < 0xff {
+            end.push(last + 1);
+            return Some(end);
+        }
+    }
+    None
+}
+
+
+fn get_prefixed(storage: &dyn Storage, prefix: &[u8], account_id: &str, key: &[u8]) -> Option<Vec<u8>> {
+    if key == ACCOUNT_ID_KEY {
+        return Some(account_id.as_bytes().to_vec());
+    }
+    storage.get(&prefixed(prefix, key))
+}
+
+
+fn range_prefixed<'a>(
+    storage: &'a dyn Storage,
+    prefix: &[u8],
+    start: Option<&[u8]>,
+    end: Option<&[u8]>,
+    order: Order,
+) -> Box<dyn Iterator<Item = (Vec<u8>, Vec<u8>)> + 'a> {
+    let start = prefixed(prefix, start.unwrap_or_default());
+    let end = match end {
+        Some(e) => Some(prefixed(prefix, e)),
+        None => prefix_end(prefix),
+    };
+    let offset = prefix.len();
+    let iter = storage
+        .range(Some(&start), end.as_deref(), order)
+        .map(move |(k, v)| (k[offset..].to_vec(), v));
+    Box::new(iter)
+}
+
+
+
+/// Mutable view of a storage scoped to a single account hosted by a shared contract
+/// such as a registry or a factory. Can be passed to any storage helper of the library
+/// in place of the contract storage to operate on credentials of that account only
+pub struct AccountStorage<'a> {
+    storage: &'a mut dyn Storage,
+    prefix: Vec<u8>,
+    account_id: String,
+}
+
+
+impl<'a> AccountStorage<'a> {
+    pub fn new(storage: &'a mut dyn Storage, account_id: &str) -> Self {
+        Self {
+            storage,
+            prefix: account_prefix(account_id),
+            account_id: account_id.to_string(),
+        }
+    }
+}
+
+
+impl<'a> Storage for AccountStorage<'a> {
+    fn get(&self, key: &[u8]) -> Option<Vec<u8>> {
+        get_prefixed(self.storage, &self.prefix, &self.account_id, key)
+    }
+
+    fn range<'b>(
+        &'b self,
+        start: Option<&[u8]>,
+        end: Option<&[u8]>,
+        order: Order,
+    ) -> Box<dyn Iterator<Item = (Vec<u8>, Vec<u8>)> + 'b> {
+        range_prefixed(self.storage, &self.prefix, start, end, order)
+    }
+
+    fn set(&mut self, key: &[u8], value: &[u8]) {
+        self.storage.set(&prefixed(&self.prefix, key), value);
+    }
+
+    fn remove(&mut self, key: &[u8]) {
+        self.storage.remove(&prefixed(&self.prefix, key));
+    }
+}
+
+
+
+/// Read-only version of [`AccountStorage`] for queries. Any attempt to write through it panics
+pub struct ReadonlyAccountStorage<'a> {
+    storage: &'a dyn Storage,
+    prefix: Vec<u8>,
+    account_id: String,
+}
+
+
+impl<'a> ReadonlyAccountStorage<'a> {
+    pub fn new(storage: &'a dyn Storage, account_id: &str) -> Self {
+        Self {
+            storage,
+            prefix: account_prefix(account_id),
+            account_id: account_id.to_string(),
+        }
+    }
+}
+
+
+impl<'a> Storage for ReadonlyAccountStorage<'a> {
+    fn get(&self, key: &[u8]) -> Option<Vec<u8>> {
+        get_prefixed(self.storage, &self.prefix, &self.account_id, key)
+    }
+
+    fn range<'b>(
+        &'b self,
+        start: Option<&[u8]>,
+        end: Option<&[u8]>,
+        order: Order,
+    ) -> Box<dyn Iterator<Item = (Vec<u8>, Vec<u8>)> + 'b> {
+        range_prefixed(self.storage, &self.prefix, start, end, order)
+    }
+
+    fn set(&mut self, _key: &[u8], _value: &[u8]) {
+        panic!("ReadonlyAccountStorage can't be written to");
+    }
+
+    fn remove(&mut self, _key: &[u8]) {
+        panic!("ReadonlyAccountStorage can't be written to");
+    }
+}
@@ -1,7 +1,10 @@
 #![allow(dead_code)]
 
 use secret_toolkit::storage::{Item, Keymap};
-use crate::{CredentialInfo, CredentialId};
+use crate::{AccountLock, CredentialInfo, CredentialId, LockPolicy};
+
+/// Version of the layout of the stored data
+pub const STORAGE_VERSION : Item<u16> = Item::new(b"saa_version");
 
 /// The credential ID to use by default for verifications
 pub const VERIFYING_CRED_ID : Item<CredentialId> = Item::new(b"saa_verifying_id");
@@ -9,10 +12,31 @@ pub const VERIFYING_CRED_ID : Item<CredentialId> = Item::new(b"saa_verifying_id"
 /// Mapping of credential IDs to credential additional information.
 pub const CREDENTIAL_INFOS: Keymap<CredentialId, CredentialInfo> = Keymap::new(b"saa_credentials");
 
+/// Same credentials in the layout written before versioning. Only read during the migration
+pub const LEGACY_CREDENTIAL_INFOS: Keymap<CredentialId, super::migration::LegacyCredentialInfo> = Keymap::new(b"saa_credentials");
+
 
 /// An EOA address that is authorized to actions withoit any signature
 pub const CALLER : Item<Option<String>> = Item::new(b"saa_with_caller");
 
+/// Set when the account is locked and all the signed actions are disabled
+pub const ACCOUNT_LOCK : Item<AccountLock> = Item::new(b"saa_lock");
+
+/// Custom requirements for unlocking the account. Default policy is used if not set
+pub const LOCK_POLICY : Item<LockPolicy> = Item::new(b"saa_lock_policy");
+
 /// Storage of used nonces  to prevent replay attacks. &str to boolean
 #[cfg(feature = "replay")]
 pub const ACCOUNT_NUMBER : Item<u128> = Item::new(b"saa_acc_num");
+
+/// Ring buffer of the latest signed actions under slots of `sequence % AUDIT_CAPACITY`
+#[cfg(feature = "audit")]
+pub const AUDIT_LOG: Keymap<u64, crate::audit::AuditRecord> = Keymap::new(b"saa_audit");
+
+/// Total number of recorded actions and the sequence number of the next record
+#[cfg(feature = "audit")]
+pub const AUDIT_COUNT : Item<u64> = Item::new(b"saa_audit_count");
+
+
+#[cfg(all(test, feature = "iterator"))]
+mod tests;
@@ -0,0 +1,84 @@
+use secretwasm_std::testing::MockStorage;
+use crate::{
+    storage::{
+        get_cred_info, get_credentials, get_credentials_paginated, migrate_storage, get_storage_version,
+        remove_credential, save_credential, CURRENT_STORAGE_VERSION, DEFAULT_LIMIT,
+    },
+    storage::migration::LegacyCredentialInfo,
+    AuthError, CredentialInfo, CredentialName,
+};
+use super::*;
+
+
+fn info(name: CredentialName) -> CredentialInfo {
+    CredentialInfo {
+        name,
+        hrp: None,
+        extension: None,
+        label: None,
+        created_at: None,
+        last_used: None,
+        role: None,
+    }
+}
+
+
+fn legacy(name: CredentialName, hrp: Option<&str>) -> LegacyCredentialInfo {
+    LegacyCredentialInfo { name, hrp: hrp.map(Into::into), extension: None }
+}
+
+
+#[test]
+fn legacy_infos_are_migrated() {
+    let mut storage = MockStorage::new();
+
+    // bincode values written by the releases before versioning
+    LEGACY_CREDENTIAL_INFOS.insert(&mut storage, &b"caller".to_vec(), &legacy(CredentialName::Caller, None)).unwrap();
+    LEGACY_CREDENTIAL_INFOS.insert(&mut storage, &b"key".to_vec(), &legacy(CredentialName::Secp256k1, Some("secret"))).unwrap();
+    CALLER.save(&mut storage, &Some(String::new())).unwrap();
+
+    assert_eq!(get_storage_version(&storage).unwrap(), 0);
+    assert_eq!(get_cred_info(&storage, b"key".to_vec()).unwrap_err(), AuthError::NotFound);
+    assert!(ACCOUNT_NUMBER.load(&storage).is_err());
+
+    assert_eq!(migrate_storage(&mut storage).unwrap(), 0);
+    assert_eq!(get_storage_version(&storage).unwrap(), CURRENT_STORAGE_VERSION);
+
+    assert_eq!(
+        get_cred_info(&storage, b"key".to_vec()).unwrap(), 
+        CredentialInfo { hrp: Some("secret".into()), ..info(CredentialName::Secp256k1) }
+    );
+    assert_eq!(get_credentials(&storage).unwrap().len(), 2);
+    assert_eq!(VERIFYING_CRED_ID.load(&storage).unwrap(), b"key".to_vec());
+    assert_eq!(CALLER.load(&storage).unwrap(), None);
+    assert_eq!(ACCOUNT_NUMBER.load(&storage).unwrap(), 0);
+
+    // running it again doesn't change anything
+    assert_eq!(migrate_storage(&mut storage).unwrap(), CURRENT_STORAGE_VERSION);
+    assert_eq!(get_credentials(&storage).unwrap().len(), 2);
+    assert_eq!(VERIFYING_CRED_ID.load(&storage).unwrap(), b"key".to_vec());
+}
+
+
+#[test]
+fn pages_are_sorted_and_complete() {
+    let mut storage = MockStorage::new();
+    for i in (0..400u16).rev() {
+        save_credential(&mut storage, &i.to_be_bytes().to_vec(), &info(CredentialName::Ed25519)).unwrap();
+    }
+    save_credential(&mut storage, &400u16.to_be_bytes().to_vec(), &info(CredentialName::Passkey)).unwrap();
+    // moves the last inserted item into the slot of the removed one
+    remove_credential(&mut storage, &5u16.to_be_bytes().to_vec()).unwrap();
+
+    let first = get_credentials_paginated(&storage, None, None, None).unwrap();
+    assert_eq!(first.len(), DEFAULT_LIMIT as usize);
+    assert_eq!(first[0].0.as_slice(), &0u16.to_be_bytes());
+    assert_eq!(first[5].0.as_slice(), &6u16.to_be_bytes());
+
+    let passkeys = get_credentials_paginated(&storage, None, None, Some(CredentialName::Passkey)).unwrap();
+    assert_eq!(passkeys.len(), 1);
+    assert_eq!(passkeys[0].0.as_slice(), &400u16.to_be_bytes());
+
+    let last = get_credentials_paginated(&storage, Some(398u16.to_be_bytes().to_vec()), None, None).unwrap();
+    assert_eq!(last.len(), 2);
+}
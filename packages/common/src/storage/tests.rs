@@ -0,0 +1,209 @@
+use cosmwasm_std::{testing::MockStorage, Storage};
+use crate::{CredentialInfo, CredentialName};
+use super::*;
+
+
+fn info(name: CredentialName) -> CredentialInfo {
+    CredentialInfo {
+        name,
+        hrp: None,
+        extension: None,
+        label: None,
+        created_at: None,
+        last_used: None,
+        role: None,
+    }
+}
+
+
+#[test]
+fn accounts_are_isolated() {
+    let mut storage = MockStorage::new();
+
+    save_credential(
+        &mut AccountStorage::new(&mut storage, "alice"),
+        &b"alice_key".to_vec(),
+        &info(CredentialName::Passkey)
+    ).unwrap();
+
+    save_credential(
+        &mut AccountStorage::new(&mut storage, "bob"),
+        &b"bob_key".to_vec(),
+        &info(CredentialName::EthPersonalSign)
+    ).unwrap();
+
+    increment_account_number(&mut AccountStorage::new(&mut storage, "bob")).unwrap();
+
+    let alice = ReadonlyAccountStorage::new(&storage, "alice");
+    let bob = ReadonlyAccountStorage::new(&storage, "bob");
+
+    assert!(has_credential(&alice, &b"alice_key".to_vec()));
+    assert!(!has_credential(&alice, &b"bob_key".to_vec()));
+    assert!(has_credential(&bob, &b"bob_key".to_vec()));
+
+    assert!(ACCOUNT_NUMBER.may_load(&alice).unwrap().is_none());
+    assert_eq!(ACCOUNT_NUMBER.load(&bob).unwrap(), 1);
+
+    // nothing leaks into the global (non-namespaced) keys
+    assert!(!has_credential(&storage, &b"alice_key".to_vec()));
+    assert!(get_credentials(&storage).unwrap().is_empty());
+}
+
+
+#[test]
+fn ranges_stay_within_account() {
+    let mut storage = MockStorage::new();
+
+    // "ab" would be a raw prefix of "abc" without the length prefix
+    for (account, key) in [("ab", "first"), ("ab", "second"), ("abc", "third")] {
+        save_credential(
+            &mut AccountStorage::new(&mut storage, account),
+            &key.as_bytes().to_vec(),
+            &info(CredentialName::Secp256k1)
+        ).unwrap();
+    }
+
+    let ab = get_credentials(&ReadonlyAccountStorage::new(&storage, "ab")).unwrap();
+    assert_eq!(ab.len(), 2);
+    assert_eq!(ab[0].0.as_slice(), b"first");
+    assert_eq!(ab[1].0.as_slice(), b"second");
+
+    let abc = get_credentials(&ReadonlyAccountStorage::new(&storage, "abc")).unwrap();
+    assert_eq!(abc.len(), 1);
+    assert_eq!(abc[0].0.as_slice(), b"third");
+
+    CREDENTIAL_INFOS.clear(&mut AccountStorage::new(&mut storage, "ab"));
+    assert!(get_credentials(&ReadonlyAccountStorage::new(&storage, "ab")).unwrap().is_empty());
+    assert_eq!(get_credentials(&ReadonlyAccountStorage::new(&storage, "abc")).unwrap().len(), 1);
+}
+
+
+#[test]
+fn credentials_pagination() {
+    let mut storage = MockStorage::new();
+
+    let names = [
+        CredentialName::Passkey,
+        CredentialName::EthPersonalSign,
+        CredentialName::Passkey,
+        CredentialName::Secp256k1,
+        CredentialName::Passkey,
+    ];
+    for (i, name) in names.into_iter().enumerate() {
+        save_credential(&mut storage, &vec![i as u8], &info(name)).unwrap();
+    }
+
+    assert_eq!(count_credentials(&storage, None).unwrap(), 5);
+    assert_eq!(count_credentials(&storage, Some(CredentialName::Passkey)).unwrap(), 3);
+    assert_eq!(count_credentials(&storage, Some(CredentialName::Ed25519)).unwrap(), 0);
+
+    let first = get_credentials_paginated(&storage, None, Some(2), None).unwrap();
+    assert_eq!(first.len(), 2);
+    assert_eq!(first[1].0.as_slice(), &[1u8]);
+
+    let next = get_credentials_paginated(&storage, Some(first[1].0.to_vec()), Some(2), None).unwrap();
+    assert_eq!(next.len(), 2);
+    assert_eq!(next[0].0.as_slice(), &[2u8]);
+
+    let passkeys = get_credentials_paginated(
+        &storage, Some(vec![0]), None, Some(CredentialName::Passkey)
+    ).unwrap();
+    assert_eq!(passkeys.len(), 2);
+    assert!(passkeys.iter().all(|(_, i)| i.name == CredentialName::Passkey));
+
+    // limit is capped at the maximum
+    for i in 5..(MAX_LIMIT as u8 + 10) {
+        save_credential(&mut storage, &vec![i], &info(CredentialName::Ed25519)).unwrap();
+    }
+    let all = get_credentials_paginated(&storage, None, Some(1000), None).unwrap();
+    assert_eq!(all.len(), MAX_LIMIT as usize);
+    assert_eq!(get_credentials_paginated(&storage, None, None, None).unwrap().len(), DEFAULT_LIMIT as usize);
+}
+
+
+#[test]
+fn filtered_pages_reach_every_credential() {
+    let mut storage = MockStorage::new();
+    for i in 0..400u16 {
+        save_credential(&mut storage, &i.to_be_bytes().to_vec(), &info(CredentialName::Ed25519)).unwrap();
+    }
+    save_credential(&mut storage, &400u16.to_be_bytes().to_vec(), &info(CredentialName::Passkey)).unwrap();
+
+    let passkeys = get_credentials_paginated(&storage, None, None, Some(CredentialName::Passkey)).unwrap();
+    assert_eq!(passkeys.len(), 1);
+    assert_eq!(passkeys[0].0.as_slice(), &400u16.to_be_bytes());
+
+    let last = get_credentials_paginated(&storage, Some(398u16.to_be_bytes().to_vec()), None, None).unwrap();
+    assert_eq!(last.len(), 2);
+}
+
+
+/// Layout of credential infos before the extension and metadata fields were added
+#[saa_schema::wasm_serde]
+struct LegacyCredentialInfo {
+    name: CredentialName,
+    hrp: Option<String>,
+}
+
+
+#[test]
+fn legacy_state_is_migrated() {
+    let mut storage = MockStorage::new();
+
+    let legacy_infos : cw_storage_plus::Map<Vec<u8>, LegacyCredentialInfo> = cw_storage_plus::Map::new("saa_credentials");
+    legacy_infos.save(&mut storage, b"caller".to_vec(), &LegacyCredentialInfo {
+        name: CredentialName::Caller, hrp: None
+    }).unwrap();
+    legacy_infos.save(&mut storage, b"key".to_vec(), &LegacyCredentialInfo {
+        name: CredentialName::CosmosArbitrary, hrp: Some("cosmos".into())
+    }).unwrap();
+    cw_storage_plus::Item::<String>::new("saa_with_caller").save(&mut storage, &String::new()).unwrap();
+
+    assert_eq!(get_storage_version(&storage).unwrap(), 0);
+    assert!(ACCOUNT_NUMBER.load(&storage).is_err());
+    assert!(VERIFYING_CRED_ID.load(&storage).is_err());
+
+    assert_eq!(migrate_storage(&mut storage).unwrap(), 0);
+    assert_eq!(get_storage_version(&storage).unwrap(), CURRENT_STORAGE_VERSION);
+
+    let stored = get_cred_info(&storage, b"key".to_vec()).unwrap();
+    assert_eq!(stored, CredentialInfo { hrp: Some("cosmos".into()), ..info(CredentialName::CosmosArbitrary) });
+    assert_eq!(get_credentials(&storage).unwrap().len(), 2);
+    assert_eq!(VERIFYING_CRED_ID.load(&storage).unwrap(), b"key".to_vec());
+    assert_eq!(CALLER.load(&storage).unwrap(), None);
+    assert_eq!(ACCOUNT_NUMBER.load(&storage).unwrap(), 0);
+
+    // running it again doesn't change anything
+    assert_eq!(migrate_storage(&mut storage).unwrap(), CURRENT_STORAGE_VERSION);
+    assert_eq!(VERIFYING_CRED_ID.load(&storage).unwrap(), b"key".to_vec());
+}
+
+
+#[test]
+fn newer_storage_is_rejected() {
+    let mut storage = MockStorage::new();
+    STORAGE_VERSION.save(&mut storage, &(CURRENT_STORAGE_VERSION + 1)).unwrap();
+    assert!(migrate_storage(&mut storage).is_err());
+}
+
+
+#[test]
+#[should_panic(expected = "ReadonlyAccountStorage can't be written to")]
+fn readonly_account_storage_rejects_writes() {
+    let mut storage = MockStorage::new();
+    increment_account_number(&mut AccountStorage::new(&mut storage, "alice")).unwrap();
+
+    let mut view = ReadonlyAccountStorage::new(&storage, "alice");
+    assert_eq!(ACCOUNT_NUMBER.load(&view).unwrap(), 1);
+    view.remove(ACCOUNT_NUMBER.as_slice());
+}
+
+
+#[test]
+fn views_expose_their_account() {
+    let mut storage = MockStorage::new();
+    assert_eq!(namespaced_account(&storage), None);
+    assert_eq!(namespaced_account(&ReadonlyAccountStorage::new(&storage, "alice")), Some("alice".into()));
+    assert_eq!(namespaced_account(&AccountStorage::new(&mut storage, "bob")), Some("bob".into()));
+}
+
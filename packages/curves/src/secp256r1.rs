@@ -79,7 +79,7 @@ pub mod implementation {
         if ok {
             Ok(())
         } else {
-            Err(AuthError::generic("Invalid public key format"))
+            Err(AuthError::InvalidPubkey("unsupported format".to_string()))
         }
     }
 
@@ -94,15 +94,15 @@ pub mod implementation {
         use digest::{Digest, Update};
         use p256::ecdsa::{Signature, VerifyingKey, signature::DigestVerifier};
         
-        let message_hash : [u8; 32] = message_hash.try_into().map_err(|_| AuthError::generic("Invalid message hash"))?;
-        let signature : [u8; 64] = signature.try_into().map_err(|_| AuthError::generic("Invalid signature"))?;
+        let message_hash : [u8; 32] = message_hash.try_into().map_err(|_| AuthError::InvalidLength(32, message_hash.len() as u16))?;
+        let signature : [u8; 64] = signature.try_into().map_err(|_| AuthError::MalformedSignature("expected 64 bytes".to_string()))?;
         check_pubkey(public_key)?;
 
         // Already hashed, just build Digest container
         let message_digest = Identity256::new().chain(message_hash);
 
         let mut signature = Signature::from_bytes(&signature.into())
-            .map_err(|e| AuthError::generic(e.to_string()))?;
+            .map_err(|e| AuthError::MalformedSignature(e.to_string()))?;
 
         // High-S signatures require normalization since our verification implementation
         // rejects them by default. If we had a verifier that does not restrict to
@@ -112,7 +112,7 @@ pub mod implementation {
         }
 
         let public_key = VerifyingKey::from_sec1_bytes(public_key)
-            .map_err(|e| AuthError::generic(e.to_string()))?;
+            .map_err(|e| AuthError::InvalidPubkey(e.to_string()))?;
 
         match public_key.verify_digest(message_digest, &signature) {
             Ok(()) => Ok(true),
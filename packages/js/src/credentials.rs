@@ -0,0 +1,97 @@
+use smart_account_auth::{
+    from_json, to_json_binary, messages::MsgDataToSign, AuthError, Binary, ClientData,
+    CosmosArbitrary, Credential, Ed25519, EthPersonalSign, PasskeyCredential, Secp256k1,
+    Secp256r1, Verifiable,
+};
+
+
+fn binary(base64: &str) -> Result<Binary, AuthError> {
+    Binary::from_base64(base64)
+}
+
+
+/// JSON text of the message in the format expected by the replay protection
+pub fn data_to_sign(
+    chain_id: String,
+    contract_address: String,
+    messages: Vec<serde_json::Value>,
+    nonce: String,
+    account_id: Option<String>,
+) -> Result<String, AuthError> {
+    let data = to_json_binary(&MsgDataToSign { chain_id, contract_address, messages, nonce, account_id })?;
+    Ok(String::from_utf8(data.to_vec())?)
+}
+
+
+pub fn eth_personal_sign(message: &str, signature: &str, signer: String) -> Result<Credential, AuthError> {
+    Ok(Credential::EthPersonalSign(EthPersonalSign {
+        message: binary(message)?,
+        signature: binary(signature)?,
+        signer,
+    }))
+}
+
+
+pub fn cosmos_arbitrary(pubkey: &str, signature: &str, message: &str, hrp: String) -> Result<Credential, AuthError> {
+    Ok(Credential::CosmosArbitrary(CosmosArbitrary {
+        pubkey: binary(pubkey)?,
+        signature: binary(signature)?,
+        message: binary(message)?,
+        hrp: Some(hrp),
+    }))
+}
+
+
+/// Passkey assertion with the `clientDataJSON` returned by the browser
+pub fn passkey(
+    id: String,
+    signature: &str,
+    authenticator_data: &str,
+    client_data_json: &str,
+    pubkey: &str,
+) -> Result<Credential, AuthError> {
+    let client_data : ClientData = from_json(client_data_json.as_bytes())?;
+    Ok(Credential::Passkey(PasskeyCredential {
+        id,
+        signature: binary(signature)?,
+        authenticator_data: binary(authenticator_data)?,
+        client_data,
+        user_handle: None,
+        pubkey: Some(binary(pubkey)?),
+    }))
+}
+
+
+pub fn secp256k1(pubkey: &str, signature: &str, message: &str, hrp: Option<String>) -> Result<Credential, AuthError> {
+    Ok(Credential::Secp256k1(Secp256k1 {
+        pubkey: binary(pubkey)?,
+        signature: binary(signature)?,
+        message: binary(message)?,
+        hrp,
+    }))
+}
+
+
+pub fn secp256r1(pubkey: &str, signature: &str, message: &str) -> Result<Credential, AuthError> {
+    Ok(Credential::Secp256r1(Secp256r1 {
+        pubkey: binary(pubkey)?,
+        signature: binary(signature)?,
+        message: binary(message)?,
+    }))
+}
+
+
+pub fn ed25519(pubkey: &str, signature: &str, message: &str) -> Result<Credential, AuthError> {
+    Ok(Credential::Ed25519(Ed25519 {
+        pubkey: binary(pubkey)?,
+        signature: binary(signature)?,
+        message: binary(message)?,
+    }))
+}
+
+
+/// Same checks as the ones performed by the contracts before storing or using a credential
+pub fn verify(credential: &Credential) -> Result<(), AuthError> {
+    credential.validate()?;
+    credential.verify()
+}
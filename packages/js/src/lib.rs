@@ -0,0 +1,113 @@
+//! Bindings exposing the credential construction and verification of smart account auth
+//! to JavaScript, so that clients can check the signatures with the same code as the contracts.
+//! Binary values are passed as base64 strings and credentials as objects in the JSON format of the contracts
+mod credentials;
+
+#[cfg(test)]
+mod tests;
+
+use serde::{de::DeserializeOwned, Serialize};
+use smart_account_auth::{AuthError, Credential, Verifiable};
+use wasm_bindgen::prelude::*;
+
+
+fn to_js<T: Serialize>(value: &T) -> Result<JsValue, JsError> {
+    value
+        .serialize(&serde_wasm_bindgen::Serializer::json_compatible())
+        .map_err(|e| JsError::new(&e.to_string()))
+}
+
+
+fn from_js<T: DeserializeOwned>(value: JsValue) -> Result<T, JsError> {
+    serde_wasm_bindgen::from_value(value).map_err(|e| JsError::new(&e.to_string()))
+}
+
+
+fn js_error(err: AuthError) -> JsError {
+    JsError::new(&err.to_string())
+}
+
+
+/// JSON text of the `MsgDataToSign` that the credentials must sign
+#[wasm_bindgen(js_name = dataToSign)]
+pub fn data_to_sign(
+    chain_id: String,
+    contract_address: String,
+    messages: JsValue,
+    nonce: String,
+    account_id: Option<String>,
+) -> Result<String, JsError> {
+    credentials::data_to_sign(chain_id, contract_address, from_js(messages)?, nonce, account_id).map_err(js_error)
+}
+
+
+#[wasm_bindgen(js_name = ethPersonalSign)]
+pub fn eth_personal_sign(message: &str, signature: &str, signer: String) -> Result<JsValue, JsError> {
+    to_js(&credentials::eth_personal_sign(message, signature, signer).map_err(js_error)?)
+}
+
+
+#[wasm_bindgen(js_name = cosmosArbitrary)]
+pub fn cosmos_arbitrary(pubkey: &str, signature: &str, message: &str, hrp: String) -> Result<JsValue, JsError> {
+    to_js(&credentials::cosmos_arbitrary(pubkey, signature, message, hrp).map_err(js_error)?)
+}
+
+
+/// Passkey credential with the raw `clientDataJSON` of the assertion
+#[wasm_bindgen(js_name = passkeyCredential)]
+pub fn passkey(
+    id: String,
+    signature: &str,
+    authenticator_data: &str,
+    client_data_json: &str,
+    pubkey: &str,
+) -> Result<JsValue, JsError> {
+    to_js(&credentials::passkey(id, signature, authenticator_data, client_data_json, pubkey).map_err(js_error)?)
+}
+
+
+#[wasm_bindgen]
+pub fn secp256k1(pubkey: &str, signature: &str, message: &str, hrp: Option<String>) -> Result<JsValue, JsError> {
+    to_js(&credentials::secp256k1(pubkey, signature, message, hrp).map_err(js_error)?)
+}
+
+
+#[wasm_bindgen]
+pub fn secp256r1(pubkey: &str, signature: &str, message: &str) -> Result<JsValue, JsError> {
+    to_js(&credentials::secp256r1(pubkey, signature, message).map_err(js_error)?)
+}
+
+
+#[wasm_bindgen]
+pub fn ed25519(pubkey: &str, signature: &str, message: &str) -> Result<JsValue, JsError> {
+    to_js(&credentials::ed25519(pubkey, signature, message).map_err(js_error)?)
+}
+
+
+/// Base64 encoded id under which the contracts store the credential
+#[wasm_bindgen(js_name = credentialId)]
+pub fn credential_id(credential: JsValue) -> Result<String, JsError> {
+    let credential : Credential = from_js(credential)?;
+    Ok(smart_account_auth::Binary::from(credential.id()).to_base64())
+}
+
+
+/// Validate the credential and verify its signature. Throws an error with the reason of the failure
+/// and the stable numeric `code` of the `AuthError`
+#[wasm_bindgen]
+pub fn verify(credential: JsValue) -> Result<(), JsValue> {
+    credentials::verify(&from_js(credential)?).map_err(|err| {
+        let code = err.code();
+        let error = JsValue::from(js_error(err));
+        // setting a property of a fresh Error object can't fail
+        let _ = js_sys::Reflect::set(&error, &"code".into(), &code.into());
+        error
+    })
+}
+
+
+/// Whether the credential passes the verification
+#[wasm_bindgen(js_name = isValid)]
+pub fn is_valid(credential: JsValue) -> bool {
+    from_js::<Credential>(credential).is_ok_and(|c| credentials::verify(&c).is_ok())
+}
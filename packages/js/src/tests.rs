@@ -0,0 +1,101 @@
+use smart_account_auth::{
+    client::{Ed25519Key, Secp256k1Key, Secp256r1Key},
+    from_json, to_json_binary, Binary, Credential,
+};
+
+use crate::credentials;
+
+
+fn message() -> String {
+    credentials::data_to_sign(
+        "pion-1".into(),
+        "neutron1contract".into(),
+        vec![serde_json::json!({ "bank": { "send": { "to_address": "bob", "amount": [] } } })],
+        "0".into(),
+        None,
+    ).unwrap()
+}
+
+
+#[test]
+fn data_to_sign_matches_contract_format() {
+    let data : smart_account_auth::messages::MsgDataToVerify = from_json(message().as_bytes()).unwrap();
+    assert_eq!(data.chain_id, "pion-1");
+    assert_eq!(data.contract_address, "neutron1contract");
+    assert_eq!(data.nonce, "0");
+    assert!(message().starts_with(r#"{"chain_id":"pion-1","contract_address":"neutron1contract","messages":[{"bank""#));
+    assert_eq!(data.account_id, None);
+
+    let namespaced = credentials::data_to_sign(
+        "pion-1".into(), "neutron1registry".into(), vec![], "0".into(), Some("alice".into())
+    ).unwrap();
+    let data : smart_account_auth::messages::MsgDataToVerify = from_json(namespaced.as_bytes()).unwrap();
+    assert_eq!(data.account_id, Some("alice".into()));
+}
+
+
+#[test]
+fn constructed_credentials_verify() {
+    let message = Binary::from(message().as_bytes());
+    let b64 = message.to_base64();
+    let k1 = Secp256k1Key::from_bytes(&[1; 32]).unwrap();
+    let r1 = Secp256r1Key::from_bytes(&[1; 32]).unwrap();
+    let ed = Ed25519Key::from_bytes(&[1; 32]).unwrap();
+
+    let signed = [
+        k1.eth_personal_sign(message.clone()).unwrap(),
+        k1.cosmos_arbitrary(message.clone(), "neutron").unwrap(),
+        k1.secp256k1(message.clone(), None).unwrap(),
+        r1.secp256r1(message.clone()).unwrap(),
+        ed.ed25519(message.clone()).unwrap(),
+    ];
+    for credential in signed {
+        let rebuilt = match &credential {
+            Credential::EthPersonalSign(c) => credentials::eth_personal_sign(
+                &b64, &c.signature.to_base64(), c.signer.clone()
+            ),
+            Credential::CosmosArbitrary(c) => credentials::cosmos_arbitrary(
+                &c.pubkey.to_base64(), &c.signature.to_base64(), &b64, "neutron".into()
+            ),
+            Credential::Secp256k1(c) => credentials::secp256k1(
+                &c.pubkey.to_base64(), &c.signature.to_base64(), &b64, None
+            ),
+            Credential::Secp256r1(c) => credentials::secp256r1(
+                &c.pubkey.to_base64(), &c.signature.to_base64(), &b64
+            ),
+            Credential::Ed25519(c) => credentials::ed25519(
+                &c.pubkey.to_base64(), &c.signature.to_base64(), &b64
+            ),
+            _ => unreachable!(),
+        }.unwrap();
+        assert_eq!(rebuilt, credential);
+        assert!(credentials::verify(&rebuilt).is_ok(), "{:?}", rebuilt.name());
+    }
+}
+
+
+#[test]
+fn passkey_is_built_from_client_data_json() {
+    let Credential::Passkey(passkey) = Secp256r1Key::from_bytes(&[1; 32]).unwrap()
+        .passkey(message().into_bytes(), "id", "https://example.com")
+        .unwrap() else { unreachable!() };
+    let client_data_json = to_json_binary(&passkey.client_data).unwrap();
+
+    let credential = credentials::passkey(
+        "id".into(),
+        &passkey.signature.to_base64(),
+        &passkey.authenticator_data.to_base64(),
+        core::str::from_utf8(&client_data_json).unwrap(),
+        &passkey.pubkey.clone().unwrap().to_base64(),
+    ).unwrap();
+    assert!(credentials::verify(&credential).is_ok());
+
+    let tampered = credentials::passkey(
+        "id".into(),
+        &passkey.signature.to_base64(),
+        &passkey.authenticator_data.to_base64(),
+        &core::str::from_utf8(&client_data_json).unwrap().replace("example.com", "evil.com"),
+        &passkey.pubkey.unwrap().to_base64(),
+    ).unwrap();
+    assert!(credentials::verify(&tampered).is_err());
+}
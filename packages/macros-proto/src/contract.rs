@@ -0,0 +1,149 @@
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{parse_quote, AttributeArgs, Data, DeriveInput, Lit, Meta, NestedMeta, Path, Variant};
+
+
+/// Path to the smart account auth crate given with `crate = "..."`
+fn crate_path(args: AttributeArgs) -> syn::Result<Path> {
+    let mut krate = parse_quote!(::smart_account_auth);
+    for arg in args {
+        match arg {
+            NestedMeta::Meta(Meta::NameValue(nv)) if nv.path.is_ident("crate") => match &nv.lit {
+                Lit::Str(s) => krate = s.parse()?,
+                lit => return Err(syn::Error::new_spanned(lit, "expected a path in a string literal")),
+            },
+            other => return Err(syn::Error::new_spanned(other, "unknown argument")),
+        }
+    }
+    Ok(krate)
+}
+
+
+fn append_variants(input: &mut DeriveInput, variants: Vec<Variant>) -> syn::Result<()> {
+    match &mut input.data {
+        Data::Enum(data) => {
+            data.variants.extend(variants);
+            Ok(())
+        },
+        _ => Err(syn::Error::new_spanned(&input.ident, "only enums can be extended with the account messages")),
+    }
+}
+
+
+pub fn execute(args: AttributeArgs, mut input: DeriveInput) -> syn::Result<TokenStream> {
+    let krate = crate_path(args)?;
+    append_variants(&mut input, vec![
+        parse_quote! {
+            /// Add, remove or replace the credentials with approval of the signers
+            UpdateCredentials {
+                op: #krate::UpdateOperation,
+                signers: #krate::CredentialData,
+            }
+        },
+        parse_quote! {
+            /// Execute the messages authorised by the signed data
+            ExecuteSigned {
+                msgs: Vec<::cosmwasm_std::CosmosMsg>,
+                signed: #krate::messages::SignedDataMsg,
+            }
+        },
+    ])?;
+
+    let name = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+
+    Ok(quote! {
+        #input
+
+        impl #impl_generics #name #ty_generics #where_clause {
+            /// Handle the messages added by `#[saa_execute]`. Other variants are rejected
+            pub fn dispatch_auth(
+                self,
+                deps: ::cosmwasm_std::DepsMut,
+                env: &::cosmwasm_std::Env,
+                info: &::cosmwasm_std::MessageInfo,
+            ) -> Result<::cosmwasm_std::Response, #krate::AuthError> {
+                match self {
+                    Self::UpdateCredentials { op, signers } => {
+                        let events = signers.update(op, deps.api, deps.storage, env, info, None)?;
+                        Ok(::cosmwasm_std::Response::new()
+                            .add_attribute("action", "update_credentials")
+                            .add_events(events))
+                    },
+                    Self::ExecuteSigned { msgs, signed } => {
+                        let events = #krate::contract::execute_signed(deps.api, deps.storage, env, signed, &msgs, None)?;
+                        Ok(::cosmwasm_std::Response::new()
+                            .add_attribute("action", "execute_signed")
+                            .add_events(events)
+                            .add_messages(msgs))
+                    },
+                    #[allow(unreachable_patterns)]
+                    _ => Err(#krate::AuthError::generic("Not an account message")),
+                }
+            }
+        }
+    })
+}
+
+
+/// Whether the enum derives `QueryResponses` of `cosmwasm_schema` and needs `#[returns(...)]` on the variants
+fn derives_query_responses(input: &DeriveInput) -> bool {
+    input.attrs.iter().any(|attr| 
+        attr.path.is_ident("derive") && attr.tokens.to_string().contains("QueryResponses")
+    )
+}
+
+
+pub fn query(args: AttributeArgs, mut input: DeriveInput) -> syn::Result<TokenStream> {
+    let krate = crate_path(args)?;
+    let mut variants : Vec<Variant> = vec![
+        parse_quote! {
+            /// All the stored credentials as `AccountCredentials`
+            #[returns(#krate::messages::AccountCredentials)]
+            Credentials {}
+        },
+        parse_quote! {
+            /// Nonce that the next signed message must use
+            #[returns(u128)]
+            AccountNumber {}
+        },
+        parse_quote! {
+            /// Version and the enabled features of the library as `LibraryInfo`
+            #[returns(#krate::LibraryInfo)]
+            LibraryInfo {}
+        },
+    ];
+    if !derives_query_responses(&input) {
+        variants.iter_mut().for_each(|v| v.attrs.retain(|a| !a.path.is_ident("returns")));
+    }
+    append_variants(&mut input, variants)?;
+
+    let name = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+
+    Ok(quote! {
+        #input
+
+        impl #impl_generics #name #ty_generics #where_clause {
+            /// Handle the queries added by `#[saa_query]`. Other variants are rejected
+            pub fn dispatch_auth(
+                self,
+                deps: ::cosmwasm_std::Deps,
+            ) -> Result<::cosmwasm_std::Binary, #krate::AuthError> {
+                match self {
+                    Self::Credentials {} => Ok(::cosmwasm_std::to_json_binary(
+                        &#krate::get_all_credentials(deps.storage)?
+                    )?),
+                    Self::AccountNumber {} => Ok(::cosmwasm_std::to_json_binary(
+                        &#krate::contract::account_number(deps.storage)?
+                    )?),
+                    Self::LibraryInfo {} => Ok(::cosmwasm_std::to_json_binary(
+                        &#krate::library_info()
+                    )?),
+                    #[allow(unreachable_patterns)]
+                    _ => Err(#krate::AuthError::generic("Not an account query")),
+                }
+            }
+        }
+    })
+}
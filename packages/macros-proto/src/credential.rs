@@ -0,0 +1,214 @@
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{Attribute, Data, DeriveInput, Fields, Ident, Lit, Meta, NestedMeta, Path};
+
+
+/// Options of a variant given with `#[credential(...)]`
+struct Variant {
+    ident: Ident,
+    cfgs: Vec<Attribute>,
+    /// the credential doesn't sign any message e.g. the caller
+    no_message: bool,
+    /// `fn(&Inner) -> Vec<u8>` used instead of the `message` field
+    message: Option<Path>,
+    /// `fn(Parts) -> Result<Inner, AuthError>` rebuilding the credential from the stored parts
+    construct: Option<Path>,
+}
+
+
+fn error(span: &impl quote::ToTokens, msg: &str) -> syn::Error {
+    syn::Error::new_spanned(span, msg)
+}
+
+
+fn string_path(lit: &Lit) -> syn::Result<Path> {
+    match lit {
+        Lit::Str(s) => s.parse(),
+        _ => Err(error(lit, "expected a path in a string literal")),
+    }
+}
+
+
+fn credential_options(attrs: &[Attribute]) -> syn::Result<Vec<NestedMeta>> {
+    let mut options = Vec::new();
+    for attr in attrs.iter().filter(|a| a.path.is_ident("credential")) {
+        match attr.parse_meta()? {
+            Meta::List(list) => options.extend(list.nested),
+            other => return Err(error(&other, "expected #[credential(...)]")),
+        }
+    }
+    Ok(options)
+}
+
+
+fn parse_variant(variant: &syn::Variant) -> syn::Result<Variant> {
+    match &variant.fields {
+        Fields::Unnamed(fields) if fields.unnamed.len() == 1 => {},
+        _ => return Err(error(variant, "credential variants must wrap a single credential type")),
+    }
+    let mut parsed = Variant {
+        ident: variant.ident.clone(),
+        cfgs: variant.attrs.iter().filter(|a| a.path.is_ident("cfg")).cloned().collect(),
+        no_message: false,
+        message: None,
+        construct: None,
+    };
+    for option in credential_options(&variant.attrs)? {
+        match option {
+            NestedMeta::Meta(Meta::Path(p)) if p.is_ident("no_message") => parsed.no_message = true,
+            NestedMeta::Meta(Meta::NameValue(nv)) if nv.path.is_ident("message") => {
+                parsed.message = Some(string_path(&nv.lit)?)
+            },
+            NestedMeta::Meta(Meta::NameValue(nv)) if nv.path.is_ident("construct") => {
+                parsed.construct = Some(string_path(&nv.lit)?)
+            },
+            other => return Err(error(&other, "unknown credential option")),
+        }
+    }
+    Ok(parsed)
+}
+
+
+/// Type of the parts passed to the constructors given with `#[credential(parts = "...")]` on the enum
+fn parts_type(input: &DeriveInput) -> syn::Result<Option<Path>> {
+    let mut parts = None;
+    for option in credential_options(&input.attrs)? {
+        match option {
+            NestedMeta::Meta(Meta::NameValue(nv)) if nv.path.is_ident("parts") => {
+                parts = Some(string_path(&nv.lit)?)
+            },
+            other => return Err(error(&other, "unknown credential option")),
+        }
+    }
+    Ok(parts)
+}
+
+
+pub fn derive(input: DeriveInput) -> syn::Result<TokenStream> {
+    let name = &input.ident;
+    let data = match &input.data {
+        Data::Enum(data) => data,
+        _ => return Err(error(&input, "CredentialDispatch can only be derived for enums")),
+    };
+    let variants = data.variants.iter().map(parse_variant).collect::<syn::Result<Vec<_>>>()?;
+
+    let name_arms = variants.iter().map(|Variant { ident, cfgs, .. }| quote! {
+        #(#cfgs)*
+        Self::#ident(_) => ::saa_common::CredentialName::#ident,
+    });
+
+    let supported = variants.iter().map(|Variant { ident, cfgs, .. }| quote! {
+        #(#cfgs)*
+        names.push(::saa_common::CredentialName::#ident);
+    });
+
+    let value_arms = variants.iter().map(|Variant { ident, cfgs, .. }| quote! {
+        #(#cfgs)*
+        Self::#ident(c) => c,
+    });
+
+    let message_arms = variants.iter().map(|v| {
+        let Variant { ident, cfgs, .. } = v;
+        let message = match (&v.message, v.no_message) {
+            (_, true) => quote! { ::saa_common::Vec::new() },
+            (Some(getter), _) => quote! { #getter(c) },
+            (None, _) => quote! { c.message.to_vec() },
+        };
+        quote! {
+            #(#cfgs)*
+            #[allow(unused_variables)]
+            Self::#ident(c) => #message,
+        }
+    });
+
+    let verify_arms = variants.iter().map(|Variant { ident, cfgs, .. }| quote! {
+        #(#cfgs)*
+        Self::#ident(c) => c.verify_cosmwasm(api),
+    });
+
+    let construct = parts_type(&input)?.map(|parts| {
+        let arms = variants.iter()
+            .filter_map(|v| v.construct.as_ref().map(|construct| (v, construct)))
+            .map(|(Variant { ident, cfgs, .. }, construct)| quote! {
+                #(#cfgs)*
+                ::saa_common::CredentialName::#ident => Self::#ident(#construct(parts)?),
+            });
+        quote! {
+            /// Rebuild a stored credential of the given kind from the parts of a signed message
+            #[cfg(all(feature = "wasm", feature = "storage"))]
+            pub(crate) fn construct(
+                name: ::saa_common::CredentialName, 
+                parts: #parts
+            ) -> Result<Self, ::saa_common::AuthError> {
+                Ok(match name {
+                    #(#arms)*
+                    #[allow(unreachable_patterns)]
+                    other => return Err(
+                        ::saa_common::AuthError::UnsupportedCredential(::saa_common::String::from(other.as_str()))
+                    ),
+                })
+            }
+        }
+    });
+
+    Ok(quote! {
+        impl #name {
+            /// Names of the credentials enabled by the features
+            pub fn supported_names() -> ::saa_common::Vec<::saa_common::CredentialName> {
+                let mut names = ::saa_common::Vec::new();
+                #(#supported)*
+                names
+            }
+
+            pub fn name(&self) -> ::saa_common::CredentialName {
+                match self {
+                    #(#name_arms)*
+                }
+            }
+
+            fn value(&self) -> &dyn ::saa_common::Verifiable {
+                match self {
+                    #(#value_arms)*
+                }
+            }
+
+            pub fn message(&self) -> ::saa_common::Vec<u8> {
+                match self {
+                    #(#message_arms)*
+                }
+            }
+
+            #construct
+        }
+
+        impl ::saa_common::Verifiable for #name {
+
+            fn id(&self) -> ::saa_common::CredentialId {
+                self.value().id()
+            }
+
+            fn hrp(&self) -> Option<::saa_common::String> {
+                self.value().hrp()
+            }
+
+            fn validate(&self) -> Result<(), ::saa_common::AuthError> {
+                self.value().validate()
+            }
+
+            #[cfg(feature = "native")]
+            fn verify(&self) -> Result<(), ::saa_common::AuthError> {
+                self.value().verify()
+            }
+
+            #[cfg(feature = "wasm")]
+            fn verify_cosmwasm(&self, api: &dyn ::saa_common::cosmwasm::Api) -> Result<(), ::saa_common::AuthError>  
+                where Self: Sized
+            {
+                self.validate()?;
+                match self {
+                    #(#verify_arms)*
+                }
+            }
+        }
+    })
+}
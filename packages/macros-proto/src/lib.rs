@@ -1,5 +1,8 @@
 use quote::ToTokens;
-use syn::{parse_quote, parse_macro_input, DeriveInput};
+use syn::{parse_quote, parse_macro_input, AttributeArgs, DeriveInput};
+
+mod contract;
+mod credential;
 
 
 #[proc_macro_attribute]
@@ -74,4 +77,63 @@ pub fn wasm_serde(
     let stream = expanded.into_token_stream();
 
     proc_macro::TokenStream::from(stream)
-}
\ No newline at end of file
+}
+
+/// Dispatch of an enum of credentials to the wrapped ones: `name()`, `value()`, `message()`,
+/// the `Verifiable` implementation, `construct()` for rebuilding stored credentials
+/// and `supported_names()` listing the enabled variants.
+/// 
+/// Every variant wraps a single credential and gives the `CredentialName` of the same name.
+/// The `#[cfg]` attributes of the variants are copied to the generated match arms.
+/// 
+/// Options of the variants given with `#[credential(...)]`:
+/// - `no_message` - the credential doesn't sign a message
+/// - `message = "path"` - `fn(&Inner) -> Vec<u8>` used instead of the `message` field
+/// - `construct = "path"` - `fn(Parts) -> Result<Inner, AuthError>` rebuilding the credential.
+///   Credentials without it can't be loaded from storage
+/// 
+/// `construct()` is only generated when the enum specifies the type of the parts with
+/// `#[credential(parts = "path")]` and only under `wasm` and `storage` features
+#[proc_macro_derive(CredentialDispatch, attributes(credential))]
+pub fn credential_dispatch(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    credential::derive(input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+
+/// Append the standard account variants to the `ExecuteMsg` of a contract:
+/// `UpdateCredentials { op, signers }` and `ExecuteSigned { msgs, signed }` 
+/// together with `dispatch_auth(deps, &env, &info)` handling them.
+/// 
+/// Must be placed before the derives e.g. `#[cw_serde]`. If the library has been imported
+/// under a different name specify it with `#[saa_execute(crate = "saa")]`
+#[proc_macro_attribute]
+pub fn saa_execute(
+    attr: proc_macro::TokenStream,
+    input: proc_macro::TokenStream,
+) -> proc_macro::TokenStream {
+    let args = parse_macro_input!(attr as AttributeArgs);
+    let input = parse_macro_input!(input as DeriveInput);
+    contract::execute(args, input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+
+/// Append the standard account variants to the `QueryMsg` of a contract:
+/// `Credentials {}`, `AccountNumber {}` and `LibraryInfo {}` together with `dispatch_auth(deps)` handling them.
+/// 
+/// Same placement and arguments as `#[saa_execute]`
+#[proc_macro_attribute]
+pub fn saa_query(
+    attr: proc_macro::TokenStream,
+    input: proc_macro::TokenStream,
+) -> proc_macro::TokenStream {
+    let args = parse_macro_input!(attr as AttributeArgs);
+    let input = parse_macro_input!(input as DeriveInput);
+    contract::query(args, input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
@@ -0,0 +1,171 @@
+use cosmwasm_std::{Addr, BlockInfo, Coin, CosmosMsg, Empty, Uint128};
+use cw_multi_test::{error::AnyResult, App, AppResponse, Contract, ContractWrapper, Executor};
+use saa_account::msg::{ExecuteMsg, InstantiateMsg, QueryMsg};
+use serde::{de::DeserializeOwned, Serialize};
+use smart_account_auth::{
+    client::{data_to_sign, signed_data_msg},
+    messages::{AccountCredentials, SignedDataMsg},
+    AccountLock, Binary, CredentialData, UpdateOperation,
+};
+
+use crate::TestSigner;
+
+
+/// The reference account contract wrapped for `cw-multi-test`
+pub fn account_contract() -> Box<dyn Contract<Empty>> {
+    Box::new(ContractWrapper::new(
+        saa_account::contract::execute,
+        saa_account::contract::instantiate,
+        saa_account::contract::query,
+    ))
+}
+
+
+/// Multi-test app with a stored account contract and helpers for signing the messages in the
+/// format expected by the replay protection. The helpers for the generic `instantiate`, `execute`
+/// and `query` work with any contract while the rest assume the messages of the reference contract
+pub struct AccountHarness {
+    pub app: App,
+    pub code_id: u64,
+    instances: u64,
+}
+
+
+impl Default for AccountHarness {
+    fn default() -> Self {
+        Self::new(account_contract())
+    }
+}
+
+
+impl AccountHarness {
+
+    pub fn new(contract: Box<dyn Contract<Empty>>) -> Self {
+        let mut app = App::default();
+        let code_id = app.store_code(contract);
+        Self { app, code_id, instances: 0 }
+    }
+
+    pub fn chain_id(&self) -> String {
+        self.app.block_info().chain_id
+    }
+
+    pub fn block_info(&self) -> BlockInfo {
+        self.app.block_info()
+    }
+
+    /// Move the chain forward by the given number of seconds
+    pub fn advance_time(&mut self, seconds: u64) {
+        self.app.update_block(|block| {
+            block.height += seconds / 5 + 1;
+            block.time = block.time.plus_seconds(seconds);
+        });
+    }
+
+    /// Address of the next instantiated contract as long as
+    /// all the contracts of the app are instantiated through the harness
+    pub fn next_address(&self) -> Addr {
+        Addr::unchecked(format!("contract{}", self.instances))
+    }
+
+    /// Message for the account that the signers must sign with the given nonce
+    pub fn data_to_sign<M: Serialize>(&self, account: &Addr, messages: Vec<M>, nonce: u128) -> Binary {
+        data_to_sign(&self.chain_id(), account.as_str(), messages, nonce)
+            .expect("serializing message to sign")
+    }
+
+    /// Credentials of the signers with the data signed for the account and the given nonce
+    pub fn credential_data(&self, account: &Addr, signers: &[TestSigner], nonce: u128) -> CredentialData {
+        let message = self.data_to_sign::<String>(account, vec![], nonce);
+        CredentialData {
+            credentials: signers.iter().map(|s| s.credential(message.clone())).collect(),
+            with_caller: None,
+            primary_index: None,
+            settings: None,
+        }
+    }
+
+    /// Messages signed by the signer for the account with the given nonce
+    pub fn signed_data(&self, account: &Addr, signer: &TestSigner, msgs: Vec<CosmosMsg>, nonce: u128) -> SignedDataMsg {
+        let credential = signer.credential(self.data_to_sign(account, msgs, nonce));
+        signed_data_msg(&credential).expect("building signed data")
+    }
+
+    pub fn instantiate<T: Serialize>(&mut self, sender: &str, msg: &T, funds: &[Coin]) -> AnyResult<Addr> {
+        let res = self.app.instantiate_contract(
+            self.code_id,
+            Addr::unchecked(sender),
+            msg,
+            funds,
+            "smart account",
+            None,
+        );
+        if res.is_ok() {
+            self.instances += 1;
+        }
+        res
+    }
+
+    pub fn execute<T: Serialize + std::fmt::Debug>(&mut self, sender: &str, account: &Addr, msg: &T) -> AnyResult<AppResponse> {
+        self.app.execute_contract(Addr::unchecked(sender), account.clone(), msg, &[])
+    }
+
+    pub fn query<T: Serialize, R: DeserializeOwned>(&self, account: &Addr, msg: &T) -> AnyResult<R> {
+        Ok(self.app.wrap().query_wasm_smart(account, msg)?)
+    }
+
+    /// Give the address native tokens of the given amount
+    pub fn fund(&mut self, address: &Addr, coins: Vec<Coin>) {
+        self.app.init_modules(|router, _, storage| {
+            router.bank.init_balance(storage, address, coins)
+        }).expect("funding address");
+    }
+
+    pub fn balance(&self, address: &Addr, denom: &str) -> Uint128 {
+        self.app.wrap().query_balance(address, denom).expect("querying balance").amount
+    }
+
+
+    // Helpers of the reference contract
+
+    /// Instantiate the account with the given credential data
+    pub fn create_account_with(&mut self, sender: &str, account_data: CredentialData) -> AnyResult<Addr> {
+        self.instantiate(sender, &InstantiateMsg { account_data }, &[])
+    }
+
+    /// Instantiate the account with credentials of the signers
+    pub fn create_account(&mut self, sender: &str, signers: &[TestSigner]) -> AnyResult<Addr> {
+        let data = self.credential_data(&self.next_address(), signers, 0);
+        self.create_account_with(sender, data)
+    }
+
+    /// Execute the messages signed by the signer with the current nonce of the account
+    pub fn execute_signed(&mut self, account: &Addr, signer: &TestSigner, msgs: Vec<CosmosMsg>) -> AnyResult<AppResponse> {
+        let signed = self.signed_data(account, signer, msgs.clone(), self.account_number(account));
+        self.execute("relayer", account, &ExecuteMsg::ExecuteSigned { msgs, signed })
+    }
+
+    /// Update the credentials with approval of the signers using the current nonce of the account
+    pub fn update_credentials(
+        &mut self,
+        sender: &str,
+        account: &Addr,
+        op: UpdateOperation,
+        signers: &[TestSigner],
+    ) -> AnyResult<AppResponse> {
+        let signers = self.credential_data(account, signers, self.account_number(account));
+        self.execute(sender, account, &ExecuteMsg::UpdateCredentials { op, signers })
+    }
+
+    pub fn account_number(&self, account: &Addr) -> u128 {
+        self.query(account, &QueryMsg::AccountNumber {}).expect("querying account number")
+    }
+
+    pub fn credentials(&self, account: &Addr) -> AccountCredentials {
+        self.query(account, &QueryMsg::Credentials {}).expect("querying credentials")
+    }
+
+    pub fn account_lock(&self, account: &Addr) -> Option<AccountLock> {
+        self.query(account, &QueryMsg::AccountLock {}).expect("querying account lock")
+    }
+}
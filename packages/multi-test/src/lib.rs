@@ -0,0 +1,12 @@
+//! Harness for testing smart account contracts built with the smart account auth library
+//! in `cw-multi-test`. Comes with the reference account contract that can be replaced with
+//! any contract accepting the same credentials
+mod harness;
+mod signer;
+
+pub use harness::{account_contract, AccountHarness};
+pub use signer::TestSigner;
+pub use cw_multi_test;
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,84 @@
+use smart_account_auth::{
+    client::{Ed25519Key, Secp256k1Key, Secp256r1Key},
+    hashes::sha256,
+    Binary, Credential, CredentialName, Verifiable,
+};
+
+
+/// Deterministic signer of a credential of the given kind with a key derived from the seed.
+/// Signers of different kinds never share the key even under the same seed
+#[derive(Clone, Debug, PartialEq)]
+pub struct TestSigner {
+    name: CredentialName,
+    seed: u8,
+}
+
+
+impl TestSigner {
+
+    /// Prefix of the addresses of the cosmos credentials
+    pub const HRP: &'static str = "cosmos";
+    /// Origin of the passkey credentials
+    pub const ORIGIN: &'static str = "https://localhost";
+
+    /// Panics for the kinds that can't sign the JSON of the replay data:
+    /// callers, custom credentials and Sign-In with Ethereum messages
+    pub fn new(name: CredentialName, seed: u8) -> Self {
+        assert!(
+            !matches!(name, CredentialName::Caller | CredentialName::Custom | CredentialName::Siwe),
+            "{} credentials can't be signed by the test signer", name.as_str()
+        );
+        Self { name, seed }
+    }
+
+    /// Signers of every credential kind that can sign messages
+    pub fn all(seed: u8) -> Vec<Self> {
+        [
+            CredentialName::EthPersonalSign,
+            CredentialName::CosmosArbitrary,
+            CredentialName::Passkey,
+            CredentialName::Secp256k1,
+            CredentialName::Secp256r1,
+            CredentialName::Ed25519,
+        ]
+        .into_iter()
+        .map(|name| Self::new(name, seed))
+        .collect()
+    }
+
+    /// Kind of the credentials produced by the signer
+    pub fn name(&self) -> &CredentialName {
+        &self.name
+    }
+
+    /// Seed from which the key of the signer is derived
+    pub fn seed(&self) -> u8 {
+        self.seed
+    }
+
+    /// Credential with the given message signed by the key of the signer
+    pub fn credential(&self, message: impl Into<Binary>) -> Credential {
+        let secret = sha256(format!("{}-{}", self.name.as_str(), self.seed).as_bytes());
+        let res = match self.name {
+            CredentialName::EthPersonalSign => Secp256k1Key::from_bytes(&secret)
+                .and_then(|k| k.eth_personal_sign(message)),
+            CredentialName::CosmosArbitrary => Secp256k1Key::from_bytes(&secret)
+                .and_then(|k| k.cosmos_arbitrary(message, Self::HRP)),
+            CredentialName::Secp256k1 => Secp256k1Key::from_bytes(&secret)
+                .and_then(|k| k.secp256k1(message, None)),
+            CredentialName::Passkey => Secp256r1Key::from_bytes(&secret)
+                .and_then(|k| k.passkey(message, &format!("passkey-{}", self.seed), Self::ORIGIN)),
+            CredentialName::Secp256r1 => Secp256r1Key::from_bytes(&secret)
+                .and_then(|k| k.secp256r1(message)),
+            CredentialName::Ed25519 => Ed25519Key::from_bytes(&secret)
+                .and_then(|k| k.ed25519(message)),
+            CredentialName::Caller | CredentialName::Custom | CredentialName::Siwe => unreachable!("rejected by `TestSigner::new`"),
+        };
+        res.expect("signing with a test key")
+    }
+
+    /// Id under which the credential of the signer is stored
+    pub fn id(&self) -> Binary {
+        self.credential(Binary::default()).id().into()
+    }
+}
@@ -0,0 +1,42 @@
+mod caller;
+mod credentials;
+mod replay;
+mod update;
+
+
+use cosmwasm_std::{coins, Addr, BankMsg, CosmosMsg};
+use cw_multi_test::error::AnyResult;
+use saa_account::ContractError;
+use smart_account_auth::AuthError;
+
+use crate::{AccountHarness, TestSigner};
+
+
+pub const DENOM: &str = "ucosm";
+
+
+pub fn send_msg(to: &str, amount: u128) -> CosmosMsg {
+    BankMsg::Send { to_address: to.to_string(), amount: coins(amount, DENOM) }.into()
+}
+
+
+/// Funded account with credentials of every kind under the seed 1
+pub fn setup() -> (AccountHarness, Addr) {
+    let mut harness = AccountHarness::default();
+    let account = harness.create_account("alice", &TestSigner::all(1)).unwrap();
+    harness.fund(&account, coins(1000, DENOM));
+    (harness, account)
+}
+
+
+pub fn contract_error<T: std::fmt::Debug>(res: AnyResult<T>) -> ContractError {
+    res.unwrap_err().downcast::<ContractError>().unwrap()
+}
+
+
+pub fn auth_error<T: std::fmt::Debug>(res: AnyResult<T>) -> AuthError {
+    match contract_error(res) {
+        ContractError::Auth(err) => err,
+        err => panic!("unexpected error: {err}"),
+    }
+}
@@ -0,0 +1,69 @@
+use cosmwasm_std::{coins, Addr};
+use saa_account::msg::ExecuteMsg;
+use smart_account_auth::{CredentialName, UpdateOperation};
+
+use super::*;
+
+
+fn setup_with_caller() -> (AccountHarness, Addr) {
+    let mut harness = AccountHarness::default();
+    let signers = [TestSigner::new(CredentialName::CosmosArbitrary, 1)];
+    let data = smart_account_auth::CredentialData {
+        with_caller: Some(true),
+        ..harness.credential_data(&harness.next_address(), &signers, 0)
+    };
+    let account = harness.create_account_with("alice", data).unwrap();
+    harness.fund(&account, coins(1000, DENOM));
+    (harness, account)
+}
+
+
+#[test]
+fn native_caller_executes_without_signatures() {
+    let (mut harness, account) = setup_with_caller();
+    assert!(harness.credentials(&account).native_caller);
+
+    harness.execute("alice", &account, &ExecuteMsg::Execute {
+        msgs: vec![send_msg("bob", 10)],
+    }).unwrap();
+    assert_eq!(harness.balance(&Addr::unchecked("bob"), DENOM).u128(), 10);
+    // the caller doesn't use nonces
+    assert_eq!(harness.account_number(&account), 1);
+
+    let err = auth_error(harness.execute("eve", &account, &ExecuteMsg::Execute {
+        msgs: vec![send_msg("eve", 10)],
+    }));
+    assert_eq!(err, AuthError::Unauthorized);
+}
+
+
+#[test]
+fn accounts_without_caller_require_signatures() {
+    let (mut harness, account) = setup();
+    let err = auth_error(harness.execute("alice", &account, &ExecuteMsg::Execute {
+        msgs: vec![send_msg("bob", 10)],
+    }));
+    assert_eq!(err, AuthError::Unauthorized);
+}
+
+
+#[test]
+fn native_caller_manages_credentials() {
+    let (mut harness, account) = setup_with_caller();
+    let new_signer = TestSigner::new(CredentialName::Passkey, 2);
+    let add = harness.credential_data(&account, std::slice::from_ref(&new_signer), 1);
+
+    let caller_only = smart_account_auth::CredentialData {
+        credentials: vec![],
+        with_caller: Some(true),
+        primary_index: None,
+        settings: None,
+    };
+    harness.execute("alice", &account, &ExecuteMsg::UpdateCredentials {
+        op: UpdateOperation::Add(add),
+        signers: caller_only,
+    }).unwrap();
+
+    assert_eq!(harness.credentials(&account).credentials.len(), 2);
+    harness.execute_signed(&account, &new_signer, vec![send_msg("bob", 10)]).unwrap();
+}
@@ -0,0 +1,120 @@
+use cosmwasm_schema::QueryResponses;
+use cosmwasm_std::Addr;
+use saa_account::msg::QueryMsg;
+use smart_account_auth::{CredentialName, LibraryInfo};
+
+use super::*;
+
+
+#[test]
+fn every_credential_kind_can_execute() {
+    let (mut harness, account) = setup();
+
+    let stored = harness.credentials(&account);
+    assert_eq!(stored.credentials.len(), 6);
+    assert_eq!(stored.verifying_id, TestSigner::new(CredentialName::EthPersonalSign, 1).id());
+    assert!(!stored.native_caller);
+    assert_eq!(harness.account_number(&account), 1);
+
+    for (i, signer) in TestSigner::all(1).iter().enumerate() {
+        let res = harness.execute_signed(&account, signer, vec![send_msg("bob", 10)]);
+        assert!(res.is_ok(), "{:?} failed: {:?}", signer.name(), res);
+        assert_eq!(harness.account_number(&account), i as u128 + 2);
+    }
+    assert_eq!(harness.balance(&Addr::unchecked("bob"), DENOM).u128(), 60);
+}
+
+
+#[test]
+fn unknown_credentials_are_rejected() {
+    let (mut harness, account) = setup();
+
+    for signer in TestSigner::all(2) {
+        let err = auth_error(harness.execute_signed(&account, &signer, vec![send_msg("bob", 10)]));
+        assert_eq!(err, AuthError::NotFound, "{:?}", signer.name());
+    }
+    assert_eq!(harness.account_number(&account), 1);
+}
+
+
+#[test]
+fn signature_of_other_key_is_rejected() {
+    let (mut harness, account) = setup();
+    let nonce = harness.account_number(&account);
+
+    // valid credential of another key presented under the id of a stored one
+    let stored = TestSigner::new(CredentialName::Secp256k1, 1);
+    let mut signed = harness.signed_data(
+        &account, &TestSigner::new(CredentialName::Secp256k1, 2), vec![send_msg("bob", 10)], nonce
+    );
+    signed.payload.as_mut().unwrap().credential_id = Some(stored.id().to_vec());
+
+    let res = harness.execute("relayer", &account, &saa_account::msg::ExecuteMsg::ExecuteSigned {
+        msgs: vec![send_msg("bob", 10)],
+        signed,
+    });
+    assert!(res.is_err());
+    assert_eq!(harness.account_number(&account), nonce);
+}
+
+
+#[test]
+fn signed_messages_must_match_executed() {
+    let (mut harness, account) = setup();
+    let signer = TestSigner::new(CredentialName::CosmosArbitrary, 1);
+    let signed = harness.signed_data(&account, &signer, vec![send_msg("bob", 10)], 1);
+
+    let err = auth_error(harness.execute("relayer", &account, &saa_account::msg::ExecuteMsg::ExecuteSigned {
+        msgs: vec![send_msg("eve", 1000)],
+        signed,
+    }));
+    assert_eq!(err, AuthError::MessagesMismatch);
+}
+
+
+#[test]
+fn valid_signature_query_keeps_nonce() {
+    let (harness, account) = setup();
+    let signer = TestSigner::new(CredentialName::Passkey, 1);
+
+    let signed = harness.signed_data(&account, &signer, vec![], 1);
+    let valid : bool = harness.query(&account, &QueryMsg::ValidSignature { signed }).unwrap();
+    assert!(valid);
+
+    let signed = harness.signed_data(&account, &signer, vec![], 2);
+    let valid : bool = harness.query(&account, &QueryMsg::ValidSignature { signed }).unwrap();
+    assert!(!valid);
+
+    assert_eq!(harness.account_number(&account), 1);
+}
+
+
+#[test]
+fn library_info_lists_credentials_of_the_account() {
+    let (harness, account) = setup();
+    let info : LibraryInfo = harness.query(&account, &QueryMsg::LibraryInfo {}).unwrap();
+    assert!(info.replay);
+    for signer in TestSigner::all(1) {
+        assert!(info.credentials.contains(signer.name()));
+    }
+}
+
+
+#[test]
+fn account_queries_declare_their_responses() {
+    let schemas = QueryMsg::response_schemas().unwrap();
+    for (query, response) in [
+        ("credentials", "AccountCredentials"),
+        ("account_number", "uint128"),
+        ("library_info", "LibraryInfo"),
+    ] {
+        assert_eq!(schemas[query].schema.metadata.as_ref().unwrap().title.as_deref(), Some(response));
+    }
+}
+
+
+#[test]
+#[should_panic(expected = "siwe credentials can't be signed by the test signer")]
+fn siwe_signers_are_rejected() {
+    TestSigner::new(CredentialName::Siwe, 1);
+}
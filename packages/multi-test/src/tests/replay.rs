@@ -0,0 +1,81 @@
+use cosmwasm_std::Addr;
+use saa_account::msg::ExecuteMsg;
+use smart_account_auth::{client::data_to_sign, client::signed_data_msg, CredentialName};
+
+use super::*;
+
+
+#[test]
+fn signed_messages_can_be_used_once() {
+    let (mut harness, account) = setup();
+    let signer = TestSigner::new(CredentialName::EthPersonalSign, 1);
+    let msgs = vec![send_msg("bob", 10)];
+    let signed = harness.signed_data(&account, &signer, msgs.clone(), 1);
+
+    let msg = ExecuteMsg::ExecuteSigned { msgs, signed };
+    harness.execute("relayer", &account, &msg).unwrap();
+
+    let err = auth_error(harness.execute("relayer", &account, &msg));
+    assert_eq!(err, AuthError::DifferentNonce);
+    assert_eq!(harness.account_number(&account), 2);
+    assert_eq!(harness.balance(&Addr::unchecked("bob"), DENOM).u128(), 10);
+}
+
+
+#[test]
+fn future_nonces_are_rejected() {
+    let (mut harness, account) = setup();
+    let signer = TestSigner::new(CredentialName::Ed25519, 1);
+    let msgs = vec![send_msg("bob", 10)];
+    let signed = harness.signed_data(&account, &signer, msgs.clone(), 2);
+
+    let err = auth_error(harness.execute("relayer", &account, &ExecuteMsg::ExecuteSigned {
+        msgs, signed
+    }));
+    assert_eq!(err, AuthError::DifferentNonce);
+}
+
+
+#[test]
+fn messages_are_bound_to_chain_and_contract() {
+    let (mut harness, account) = setup();
+    let other = harness.create_account("alice", &TestSigner::all(1)).unwrap();
+    harness.fund(&other, cosmwasm_std::coins(1000, DENOM));
+    let signer = TestSigner::new(CredentialName::Secp256r1, 1);
+    let msgs = vec![send_msg("bob", 10)];
+
+    // signed for the other account with the same credentials
+    let signed = harness.signed_data(&other, &signer, msgs.clone(), 1);
+    let err = auth_error(harness.execute("relayer", &account, &ExecuteMsg::ExecuteSigned {
+        msgs: msgs.clone(), signed: signed.clone()
+    }));
+    assert_eq!(err, AuthError::ContractMismatch);
+    harness.execute("relayer", &other, &ExecuteMsg::ExecuteSigned {
+        msgs: msgs.clone(), signed
+    }).unwrap();
+
+    let message = data_to_sign("other-chain", account.as_str(), msgs.clone(), 1).unwrap();
+    let signed = signed_data_msg(&signer.credential(message)).unwrap();
+    let err = auth_error(harness.execute("relayer", &account, &ExecuteMsg::ExecuteSigned {
+        msgs, signed
+    }));
+    assert_eq!(err, AuthError::ChainIdMismatch);
+}
+
+
+#[test]
+fn instantiation_requires_initial_nonce() {
+    let mut harness = AccountHarness::default();
+    let address = harness.next_address();
+
+    let data = harness.credential_data(&address, &TestSigner::all(1), 1);
+    let err = contract_error(harness.create_account_with("alice", data));
+    assert_eq!(err, ContractError::Auth(AuthError::DifferentNonce));
+
+    let data = harness.credential_data(&Addr::unchecked("contract9"), &TestSigner::all(1), 0);
+    let err = contract_error(harness.create_account_with("alice", data));
+    assert_eq!(err, ContractError::Auth(AuthError::ContractMismatch));
+
+    let data = harness.credential_data(&address, &TestSigner::all(1), 0);
+    assert_eq!(harness.create_account_with("alice", data).unwrap(), address);
+}
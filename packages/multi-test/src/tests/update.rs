@@ -0,0 +1,98 @@
+use cosmwasm_std::Addr;
+use saa_account::msg::ExecuteMsg;
+use smart_account_auth::{CredentialName, CredentialRole, CredentialSettings, UpdateOperation};
+
+use super::*;
+
+
+#[test]
+fn credentials_can_be_added_and_removed() {
+    let (mut harness, account) = setup();
+    let admin = TestSigner::new(CredentialName::EthPersonalSign, 1);
+    let new_signer = TestSigner::new(CredentialName::Ed25519, 2);
+
+    let add = harness.credential_data(&account, std::slice::from_ref(&new_signer), 1);
+    harness.update_credentials("relayer", &account, UpdateOperation::Add(add), std::slice::from_ref(&admin)).unwrap();
+    assert_eq!(harness.credentials(&account).credentials.len(), 7);
+    assert_eq!(harness.account_number(&account), 2);
+
+    harness.execute_signed(&account, &new_signer, vec![send_msg("bob", 10)]).unwrap();
+
+    let remove = harness.credential_data(&account, std::slice::from_ref(&new_signer), 3);
+    harness.update_credentials("relayer", &account, UpdateOperation::Remove(remove), &[admin]).unwrap();
+    assert_eq!(harness.credentials(&account).credentials.len(), 6);
+
+    let err = auth_error(harness.execute_signed(&account, &new_signer, vec![send_msg("bob", 10)]));
+    assert_eq!(err, AuthError::NotFound);
+}
+
+
+#[test]
+fn verifying_credential_can_be_rotated() {
+    let (mut harness, account) = setup();
+    let admin = TestSigner::new(CredentialName::EthPersonalSign, 1);
+    let passkey = TestSigner::new(CredentialName::Passkey, 1);
+
+    harness.update_credentials(
+        "relayer", &account, UpdateOperation::SetPrimary(passkey.id()), std::slice::from_ref(&admin)
+    ).unwrap();
+    assert_eq!(harness.credentials(&account).verifying_id, passkey.id());
+
+    let replacement = TestSigner::new(CredentialName::Secp256r1, 3);
+    let add = harness.credential_data(&account, std::slice::from_ref(&replacement), 2);
+    harness.update_credentials(
+        "relayer", &account, UpdateOperation::Replace { add, remove: vec![admin.id()] }, &[passkey]
+    ).unwrap();
+
+    let err = auth_error(harness.execute_signed(&account, &admin, vec![send_msg("bob", 10)]));
+    assert_eq!(err, AuthError::NotFound);
+    harness.execute_signed(&account, &replacement, vec![send_msg("bob", 10)]).unwrap();
+}
+
+
+#[test]
+fn executors_can_not_manage_credentials() {
+    let mut harness = AccountHarness::default();
+    let admin = TestSigner::new(CredentialName::CosmosArbitrary, 1);
+    let executor = TestSigner::new(CredentialName::Secp256k1, 1);
+
+    let data = smart_account_auth::CredentialData {
+        settings: Some(vec![
+            CredentialSettings::default(),
+            CredentialSettings { role: Some(CredentialRole::Executor), ..Default::default() },
+        ]),
+        ..harness.credential_data(&harness.next_address(), &[admin, executor.clone()], 0)
+    };
+    let account = harness.create_account_with("alice", data).unwrap();
+    harness.fund(&account, cosmwasm_std::coins(100, DENOM));
+
+    let add = harness.credential_data(&account, &[TestSigner::new(CredentialName::Ed25519, 2)], 1);
+    let err = auth_error(harness.update_credentials("relayer", &account, UpdateOperation::Add(add), std::slice::from_ref(&executor)));
+    assert_eq!(err, AuthError::Unauthorized);
+
+    harness.execute_signed(&account, &executor, vec![send_msg("bob", 10)]).unwrap();
+}
+
+
+#[test]
+fn locked_account_rejects_actions() {
+    let (mut harness, account) = setup();
+    let admins = [
+        TestSigner::new(CredentialName::EthPersonalSign, 1),
+        TestSigner::new(CredentialName::CosmosArbitrary, 1),
+    ];
+
+    let signed = harness.signed_data(&account, &admins[0], vec![], 1);
+    harness.execute("relayer", &account, &ExecuteMsg::Lock { signed }).unwrap();
+    assert!(harness.account_lock(&account).is_some());
+
+    let err = auth_error(harness.execute_signed(&account, &admins[1], vec![send_msg("bob", 10)]));
+    assert_eq!(err, AuthError::Locked);
+
+    let signers = harness.credential_data(&account, &admins, 2);
+    harness.execute("relayer", &account, &ExecuteMsg::Unlock { signers }).unwrap();
+    assert!(harness.account_lock(&account).is_none());
+
+    harness.execute_signed(&account, &admins[1], vec![send_msg("bob", 10)]).unwrap();
+    assert_eq!(harness.balance(&Addr::unchecked("bob"), DENOM).u128(), 10);
+}
@@ -2,7 +2,7 @@
 
 use saa_macros_proto;
 
-pub use saa_macros_proto::wasm_serde;
+pub use saa_macros_proto::{wasm_serde, CredentialDispatch, saa_execute, saa_query};
 
 
 pub use serde;
@@ -10,6 +10,9 @@ pub use serde;
 #[cfg(feature = "cosmwasm")]
 pub use schemars;
 
+#[cfg(feature = "typescript")]
+pub mod ts;
+
 #[cfg(feature = "solana")]
 pub use borsh;
 
@@ -0,0 +1,276 @@
+//! Emitter of TypeScript definitions from the JSON schemas of the exported types
+
+use std::collections::{BTreeMap, BTreeSet};
+use schemars::{
+    gen::SchemaGenerator, JsonSchema,
+    schema::{InstanceType, RootSchema, Schema, SchemaObject, SingleOrVec},
+};
+
+
+/// Name of the definition that stands for a generic parameter
+const TYPE_PARAM: &str = "__TypeParam";
+
+const DEFINITIONS: &str = "#/definitions/";
+
+
+/// Placeholder for a generic parameter of an exported type e.g. `MsgDataToSign<TypeParam>`
+pub struct TypeParam;
+
+
+impl JsonSchema for TypeParam {
+    fn schema_name() -> String {
+        TYPE_PARAM.to_string()
+    }
+
+    fn json_schema(_: &mut SchemaGenerator) -> Schema {
+        Schema::Bool(true)
+    }
+}
+
+
+/// Collects the root schemas together with their definitions and renders them
+/// as TypeScript declarations sorted by name
+#[derive(Default)]
+pub struct TsGenerator {
+    declarations: BTreeMap<String, String>,
+    aliases: BTreeMap<String, String>,
+}
+
+
+impl TsGenerator {
+
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Use a different TypeScript name for a definition e.g. `CosmosMsg_for_Empty` -> `CosmosMsg`
+    pub fn alias(mut self, schema_name: &str, ts_name: &str) -> Self {
+        self.aliases.insert(schema_name.to_string(), ts_name.to_string());
+        self
+    }
+
+    /// Declare the root type under the given name along with all of its definitions
+    pub fn add(&mut self, name: &str, root: &RootSchema) -> &mut Self {
+        self.add_root(name, None, root)
+    }
+
+    /// Declare a root generated for `Type<TypeParam>` as a generic type
+    /// with the given parameter name and its default TypeScript type
+    pub fn add_generic(&mut self, name: &str, param: (&str, &str), root: &RootSchema) -> &mut Self {
+        self.add_root(name, Some(param), root)
+    }
+
+    /// Declarations separated by empty lines behind a header warning against manual edits
+    pub fn render(&self) -> String {
+        let mut out = String::from(
+            "// Generated from the JSON schemas of the Rust types. Do not edit manually\n\n"
+        );
+        out.push_str(&self.declarations.values().cloned().collect::<Vec<_>>().join("\n\n"));
+        out.push('\n');
+        out
+    }
+
+    fn add_root(&mut self, name: &str, param: Option<(&str, &str)>, root: &RootSchema) -> &mut Self {
+        let declaration = self.declare(name, &root.schema, param);
+        self.declarations.insert(name.to_string(), declaration);
+
+        for (def_name, def) in root.definitions.iter() {
+            if def_name == TYPE_PARAM {
+                continue;
+            }
+            let name = self.name(def_name);
+            if self.declarations.contains_key(&name) {
+                continue;
+            }
+            let declaration = match def {
+                Schema::Object(obj) => self.declare(&name, obj, None),
+                Schema::Bool(_) => format!("export type {} = unknown;", name),
+            };
+            self.declarations.insert(name, declaration);
+        }
+        self
+    }
+
+    fn name(&self, schema_name: &str) -> String {
+        self.aliases.get(schema_name).cloned().unwrap_or(schema_name.to_string())
+    }
+
+    fn declare(&self, name: &str, schema: &SchemaObject, param: Option<(&str, &str)>) -> String {
+        let param_name = param.map(|(p, _)| p);
+        let generics = param
+            .map(|(p, default)| format!("<{} = {}>", p, default))
+            .unwrap_or_default();
+
+        let mut out = doc_comment(schema, "");
+
+        let properties = schema.object.as_ref().filter(|o| !o.properties.is_empty());
+        match (properties, schema.subschemas.as_ref()) {
+            (Some(object), None) => {
+                out.push_str(&format!("export interface {}{} {{\n", name, generics));
+                for (key, prop) in object.properties.iter() {
+                    if let Schema::Object(obj) = prop {
+                        out.push_str(&doc_comment(obj, "    "));
+                    }
+                    out.push_str(&format!(
+                        "    {}{}: {};\n",
+                        property_key(key),
+                        optional_mark(key, &object.required),
+                        self.ts_type(prop, param_name)
+                    ));
+                }
+                out.push('}');
+            },
+            _ => {
+                let variants = schema.subschemas.as_ref()
+                    .and_then(|s| s.one_of.as_ref())
+                    .filter(|v| v.len() > 1);
+
+                let body = match variants {
+                    Some(variants) => variants.iter()
+                        .map(|v| format!("\n    | {}", self.ts_type(v, param_name)))
+                        .collect::<String>(),
+                    None => format!(" {}", self.ts_object_type(schema, param_name)),
+                };
+                out.push_str(&format!("export type {}{} ={};", name, generics, body));
+            }
+        }
+        out
+    }
+
+    fn ts_type(&self, schema: &Schema, param: Option<&str>) -> String {
+        match schema {
+            Schema::Bool(true) => "unknown".to_string(),
+            Schema::Bool(false) => "never".to_string(),
+            Schema::Object(obj) => self.ts_object_type(obj, param),
+        }
+    }
+
+    fn ts_object_type(&self, schema: &SchemaObject, param: Option<&str>) -> String {
+        if let Some(reference) = schema.reference.as_ref() {
+            let name = reference.trim_start_matches(DEFINITIONS);
+            return match (name == TYPE_PARAM, param) {
+                (true, Some(param)) => param.to_string(),
+                (true, None) => "unknown".to_string(),
+                _ => self.name(name),
+            };
+        }
+
+        if let Some(sub) = schema.subschemas.as_ref() {
+            let members = sub.all_of.as_ref()
+                .filter(|all| all.len() == 1)
+                .or(sub.one_of.as_ref())
+                .or(sub.any_of.as_ref());
+            if let Some(members) = members {
+                return union(members.iter().map(|m| self.ts_type(m, param)));
+            }
+        }
+
+        if let Some(values) = schema.enum_values.as_ref() {
+            return union(values.iter().map(|v| v.to_string()));
+        }
+        if let Some(value) = schema.const_value.as_ref() {
+            return value.to_string();
+        }
+
+        match schema.instance_type.as_ref() {
+            Some(SingleOrVec::Single(ty)) => self.ts_instance_type(ty, schema, param),
+            Some(SingleOrVec::Vec(types)) => union(
+                types.iter().map(|ty| self.ts_instance_type(ty, schema, param))
+            ),
+            None => "unknown".to_string(),
+        }
+    }
+
+    fn ts_instance_type(&self, ty: &InstanceType, schema: &SchemaObject, param: Option<&str>) -> String {
+        match ty {
+            InstanceType::Null => "null".to_string(),
+            InstanceType::Boolean => "boolean".to_string(),
+            InstanceType::Integer | InstanceType::Number => "number".to_string(),
+            InstanceType::String => "string".to_string(),
+            InstanceType::Array => match schema.array.as_ref().and_then(|a| a.items.as_ref()) {
+                Some(SingleOrVec::Single(item)) => {
+                    let item = self.ts_type(item, param);
+                    if item.contains(" | ") {
+                        format!("({})[]", item)
+                    } else {
+                        format!("{}[]", item)
+                    }
+                },
+                Some(SingleOrVec::Vec(items)) => format!(
+                    "[{}]",
+                    items.iter().map(|i| self.ts_type(i, param)).collect::<Vec<_>>().join(", ")
+                ),
+                None => "unknown[]".to_string(),
+            },
+            InstanceType::Object => match schema.object.as_ref() {
+                Some(object) if !object.properties.is_empty() => format!(
+                    "{{ {} }}",
+                    object.properties.iter()
+                        .map(|(key, prop)| format!(
+                            "{}{}: {}",
+                            property_key(key),
+                            optional_mark(key, &object.required),
+                            self.ts_type(prop, param)
+                        ))
+                        .collect::<Vec<_>>()
+                        .join("; ")
+                ),
+                Some(object) => match object.additional_properties.as_deref() {
+                    Some(Schema::Bool(false)) => "Record<string, never>".to_string(),
+                    Some(values) => format!("Record<string, {}>", self.ts_type(values, param)),
+                    None => "Record<string, unknown>".to_string(),
+                },
+                None => "Record<string, unknown>".to_string(),
+            },
+        }
+    }
+}
+
+
+fn union(members: impl Iterator<Item = String>) -> String {
+    let mut seen = Vec::new();
+    for member in members {
+        if !seen.contains(&member) {
+            seen.push(member);
+        }
+    }
+    seen.join(" | ")
+}
+
+
+fn optional_mark(key: &str, required: &BTreeSet<String>) -> &'static str {
+    if required.contains(key) { "" } else { "?" }
+}
+
+
+fn property_key(key: &str) -> String {
+    let valid = key.chars().enumerate().all(|(i, c)|
+        c == '_' || c == '$' || c.is_ascii_alphabetic() || (i > 0 && c.is_ascii_digit())
+    );
+    if valid && !key.is_empty() {
+        key.to_string()
+    } else {
+        format!("{:?}", key)
+    }
+}
+
+
+fn doc_comment(schema: &SchemaObject, indent: &str) -> String {
+    // only the first paragraph to leave out the examples and other details
+    let description = schema.metadata.as_ref()
+        .and_then(|m| m.description.as_ref())
+        .and_then(|d| d.trim().split("\n\n").next())
+        .filter(|d| !d.is_empty());
+
+    match description {
+        Some(d) if d.contains('\n') => {
+            let lines = d.lines()
+                .map(|l| format!("{} * {}", indent, l).trim_end().to_string())
+                .collect::<Vec<_>>()
+                .join("\n");
+            format!("{indent}/**\n{lines}\n{indent} */\n")
+        },
+        Some(d) => format!("{}/** {} */\n", indent, d),
+        None => String::new(),
+    }
+}